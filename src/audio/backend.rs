@@ -0,0 +1,34 @@
+//! The trait [`AudioModule`](super::AudioModule) drives, separated out so
+//! [`super::CpalBackend`] and [`super::NullBackend`] can live in their own
+//! files without a cyclic `mod.rs` import.
+
+/// Called once per output buffer with an interleaved `channels`-wide slice
+/// to fill with samples. Boxed and `Send` because the real backend calls
+/// it from cpal's own audio thread, not the caller's.
+pub type AudioCallback = Box<dyn FnMut(&mut [f32]) + Send>;
+
+pub trait AudioBackend {
+    /// Opens the device (or does nothing, for [`super::NullBackend`]) and
+    /// begins calling `callback` with output buffers at `sample_rate`.
+    fn start(
+        &mut self,
+        sample_rate: u32,
+        channels: u16,
+        callback: AudioCallback,
+    ) -> anyhow::Result<()>;
+
+    /// Stops calling the callback and releases the device, if any. Safe to
+    /// call more than once, since [`super::AudioModule::drop`] always
+    /// calls it regardless of whether `stop` was already called manually.
+    fn stop(&mut self);
+
+    /// The sample rate `start` actually settled on, once a device is open.
+    fn sample_rate(&self) -> u32;
+
+    /// The output latency `start` actually achieved, once a device is
+    /// open, if the backend knows it. `None` for backends (like
+    /// [`super::NullBackend`]) that never open a real device.
+    fn latency(&self) -> Option<std::time::Duration> {
+        None
+    }
+}
@@ -0,0 +1,108 @@
+//! The real [`AudioBackend`], backed by `cpal`.
+
+use std::time::Duration;
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+
+use super::backend::{AudioBackend, AudioCallback};
+
+#[derive(Default)]
+pub struct CpalBackend {
+    host: Option<String>,
+    requested_buffer_size: Option<u32>,
+    exclusive: bool,
+    stream: Option<cpal::Stream>,
+    sample_rate: u32,
+    buffer_frames: Option<u32>,
+}
+
+impl CpalBackend {
+    pub fn new(host: Option<String>, requested_buffer_size: Option<u32>, exclusive: bool) -> Self {
+        Self {
+            host,
+            requested_buffer_size,
+            exclusive,
+            stream: None,
+            sample_rate: 0,
+            buffer_frames: None,
+        }
+    }
+
+    fn resolve_host(&self) -> cpal::Host {
+        let Some(name) = &self.host else {
+            return cpal::default_host();
+        };
+        let found = cpal::available_hosts()
+            .into_iter()
+            .find(|id| id.name().eq_ignore_ascii_case(name))
+            .and_then(|id| cpal::host_from_id(id).ok());
+        found.unwrap_or_else(|| {
+            log::warn!("no audio host named {name:?} available, falling back to the default host");
+            cpal::default_host()
+        })
+    }
+}
+
+impl AudioBackend for CpalBackend {
+    fn start(
+        &mut self,
+        sample_rate: u32,
+        channels: u16,
+        mut callback: AudioCallback,
+    ) -> anyhow::Result<()> {
+        let host = self.resolve_host();
+
+        if self.exclusive {
+            // cpal's cross-platform `Device`/`Host` traits don't expose
+            // host-specific exclusive mode — true WASAPI exclusive access
+            // needs unsafe, Windows-only calls this backend doesn't make
+            // yet, so the request is honored as "lowest-latency shared
+            // mode" rather than silently ignored.
+            log::warn!(
+                "--exclusive was requested, but this backend doesn't implement host-specific \
+                 exclusive-mode access yet; opening {} in shared mode instead",
+                host.id().name()
+            );
+        }
+
+        let device = host
+            .default_output_device()
+            .ok_or_else(|| anyhow::anyhow!("no default audio output device on host {}", host.id().name()))?;
+
+        let buffer_size = match self.requested_buffer_size {
+            Some(frames) => cpal::BufferSize::Fixed(frames),
+            None => cpal::BufferSize::Default,
+        };
+        let config = cpal::StreamConfig {
+            channels,
+            sample_rate: cpal::SampleRate(sample_rate),
+            buffer_size,
+        };
+
+        let stream = device.build_output_stream(
+            &config,
+            move |data: &mut [f32], _info: &cpal::OutputCallbackInfo| callback(data),
+            |err| log::error!("audio output stream error: {err}"),
+            None,
+        )?;
+        stream.play()?;
+
+        self.sample_rate = sample_rate;
+        self.buffer_frames = self.requested_buffer_size;
+        self.stream = Some(stream);
+        Ok(())
+    }
+
+    fn stop(&mut self) {
+        self.stream = None;
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn latency(&self) -> Option<Duration> {
+        let frames = self.buffer_frames?;
+        Some(Duration::from_secs_f64(frames as f64 / self.sample_rate.max(1) as f64))
+    }
+}
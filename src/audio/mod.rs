@@ -0,0 +1,78 @@
+//! The audio output backend: something that can push rendered samples to
+//! the outside world, either a real device (`CpalBackend`) or nothing at
+//! all (`NullBackend`, for CI and the exporter, which have no business
+//! opening hardware just to pull samples out of a graph).
+//!
+//! [`AudioModule`] is deliberately thin — it owns a backend and starts it
+//! with a caller-supplied callback, rather than knowing anything about
+//! mixing or node graphs itself, so the same backend selection works
+//! whether the callback is "the real mixer" or a test stub.
+
+mod backend;
+mod cpal_backend;
+mod null;
+mod stats;
+
+pub use backend::{AudioBackend, AudioCallback};
+pub use cpal_backend::CpalBackend;
+pub use null::NullBackend;
+pub use stats::{AudioStats, AudioStatsSnapshot};
+
+pub struct AudioModule {
+    backend: Box<dyn AudioBackend>,
+    stats: AudioStats,
+}
+
+impl AudioModule {
+    pub fn init(
+        mut backend: Box<dyn AudioBackend>,
+        sample_rate: u32,
+        channels: u16,
+        callback: AudioCallback,
+    ) -> anyhow::Result<Self> {
+        let stats = AudioStats::default();
+        let instrumented = stats.wrap(sample_rate, channels, callback);
+        backend.start(sample_rate, channels, instrumented)?;
+        Ok(Self { backend, stats })
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.backend.sample_rate()
+    }
+
+    /// A handle to this module's render-time/xrun counters, shareable
+    /// with `Stats.audio()` and the TUI.
+    pub fn stats(&self) -> AudioStats {
+        self.stats.clone()
+    }
+
+    pub fn stop(&mut self) {
+        self.backend.stop();
+    }
+}
+
+impl Drop for AudioModule {
+    fn drop(&mut self) {
+        self.backend.stop();
+    }
+}
+
+/// Picks [`NullBackend`] when `offline` is set or `OCTMM_AUDIO_BACKEND=null`
+/// is in the environment, and a [`CpalBackend`] configured from `output`
+/// otherwise. The env var exists for CI runners that can't pass
+/// `--render`/`--offline` through whatever wraps `cargo test`, but still
+/// need every test to skip real hardware.
+pub fn select_backend(offline: bool, output: &crate::output::OutputConfig) -> Box<dyn AudioBackend> {
+    let forced_null = std::env::var("OCTMM_AUDIO_BACKEND")
+        .map(|value| value == "null")
+        .unwrap_or(false);
+    if offline || forced_null {
+        Box::new(NullBackend::default())
+    } else {
+        Box::new(CpalBackend::new(
+            output.host.clone(),
+            output.buffer_size,
+            output.exclusive,
+        ))
+    }
+}
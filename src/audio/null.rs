@@ -0,0 +1,49 @@
+//! An [`AudioBackend`] that never opens a device and never calls its
+//! callback — selected for tests and the offline exporter, which need the
+//! rest of the audio stack to construct and run without a sound card
+//! present, rather than a fake device that still spins up a real stream.
+
+use super::backend::{AudioBackend, AudioCallback};
+
+#[derive(Default)]
+pub struct NullBackend {
+    sample_rate: u32,
+}
+
+impl AudioBackend for NullBackend {
+    fn start(
+        &mut self,
+        sample_rate: u32,
+        _channels: u16,
+        _callback: AudioCallback,
+    ) -> anyhow::Result<()> {
+        self.sample_rate = sample_rate;
+        Ok(())
+    }
+
+    fn stop(&mut self) {}
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn start_never_invokes_the_callback() {
+        let mut backend = NullBackend::default();
+        backend
+            .start(48_000, 2, Box::new(|_| panic!("null backend called back")))
+            .unwrap();
+    }
+
+    #[test]
+    fn reports_the_sample_rate_it_was_given() {
+        let mut backend = NullBackend::default();
+        backend.start(44_100, 2, Box::new(|_| {})).unwrap();
+        assert_eq!(backend.sample_rate(), 44_100);
+    }
+}
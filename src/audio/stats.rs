@@ -0,0 +1,100 @@
+//! Per-buffer CPU/xrun instrumentation for the output callback, so
+//! `Stats.audio()` and (eventually) the TUI can show render headroom
+//! instead of only finding out about trouble once the audio glitches.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::backend::AudioCallback;
+
+#[derive(Default)]
+struct Counters {
+    buffers: AtomicU64,
+    xruns: AtomicU64,
+    total_render_nanos: AtomicU64,
+    max_render_nanos: AtomicU64,
+}
+
+/// Shared between the output callback (which records each buffer) and
+/// whoever reads the numbers back out (`Stats.audio()`, the TUI) — an
+/// `Arc` rather than this crate's usual `Rc`, since the real callback
+/// runs on cpal's own audio thread, not the Lua thread.
+#[derive(Clone, Default)]
+pub struct AudioStats(Arc<Counters>);
+
+impl AudioStats {
+    /// Wraps `callback`, timing each call and counting it as an xrun if
+    /// it took longer than the buffer's own real-time budget (its frame
+    /// count divided by `sample_rate`) — the deadline a buffer has to be
+    /// produced within to avoid an actual underrun.
+    pub fn wrap(&self, sample_rate: u32, channels: u16, mut callback: AudioCallback) -> AudioCallback {
+        let stats = self.clone();
+        Box::new(move |data: &mut [f32]| {
+            let start = Instant::now();
+            callback(data);
+            let elapsed = start.elapsed();
+
+            let frames = data.len() as f64 / channels.max(1) as f64;
+            let budget = Duration::from_secs_f64(frames / sample_rate.max(1) as f64);
+
+            stats.0.buffers.fetch_add(1, Ordering::Relaxed);
+            stats
+                .0
+                .total_render_nanos
+                .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+            stats
+                .0
+                .max_render_nanos
+                .fetch_max(elapsed.as_nanos() as u64, Ordering::Relaxed);
+            if elapsed > budget {
+                stats.0.xruns.fetch_add(1, Ordering::Relaxed);
+            }
+        })
+    }
+
+    pub fn snapshot(&self) -> AudioStatsSnapshot {
+        let buffers = self.0.buffers.load(Ordering::Relaxed);
+        let total_nanos = self.0.total_render_nanos.load(Ordering::Relaxed);
+        AudioStatsSnapshot {
+            buffers,
+            xruns: self.0.xruns.load(Ordering::Relaxed),
+            mean_render: Duration::from_nanos(if buffers > 0 { total_nanos / buffers } else { 0 }),
+            max_render: Duration::from_nanos(self.0.max_render_nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AudioStatsSnapshot {
+    pub buffers: u64,
+    pub xruns: u64,
+    pub mean_render: Duration,
+    pub max_render: Duration,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_callback_within_budget_is_not_counted_as_an_xrun() {
+        let stats = AudioStats::default();
+        let mut wrapped = stats.wrap(48_000, 2, Box::new(|_| {}));
+        wrapped(&mut [0.0; 256]);
+        assert_eq!(stats.snapshot().xruns, 0);
+        assert_eq!(stats.snapshot().buffers, 1);
+    }
+
+    #[test]
+    fn a_callback_that_overruns_its_budget_counts_as_an_xrun() {
+        let stats = AudioStats::default();
+        // One frame at a very high sample rate gives a microsecond-scale
+        // budget, so a short real sleep reliably blows past it.
+        let mut wrapped = stats.wrap(1_000_000, 1, Box::new(|_| {
+            std::thread::sleep(Duration::from_millis(5));
+        }));
+        wrapped(&mut [0.0; 1]);
+        assert_eq!(stats.snapshot().xruns, 1);
+    }
+}
@@ -0,0 +1,96 @@
+//! Time-keyed parameter automation: ramps a `fundsp` [`Shared`] value
+//! through a list of keyframes, for parameters that should change over
+//! the course of a performance without a hand-written Lua timer.
+
+use fundsp::hacker::Shared;
+
+/// A single `(time, value)` keyframe.
+#[derive(Debug, Clone, Copy)]
+pub struct Keyframe {
+    pub at: f64,
+    pub value: f64,
+}
+
+/// Linearly interpolates a [`Shared`] target through a sorted list of
+/// keyframes as time advances.
+pub struct AutomationLane {
+    keyframes: Vec<Keyframe>,
+    target: Shared,
+}
+
+impl AutomationLane {
+    pub fn new(target: Shared) -> Self {
+        Self {
+            keyframes: Vec::new(),
+            target,
+        }
+    }
+
+    /// Adds a keyframe, keeping the list sorted by time.
+    pub fn add_point(&mut self, at: f64, value: f64) {
+        let index = self
+            .keyframes
+            .partition_point(|k| k.at < at);
+        self.keyframes.insert(index, Keyframe { at, value });
+    }
+
+    /// Writes the interpolated value at `time` into the target. Before
+    /// the first keyframe or after the last, holds that keyframe's value.
+    pub fn advance(&mut self, time: f64) {
+        self.target.set_value(self.value_at(time) as f32);
+    }
+
+    fn value_at(&self, time: f64) -> f64 {
+        match self.keyframes.as_slice() {
+            [] => self.target.value() as f64,
+            [only] => only.value,
+            keyframes => {
+                if time <= keyframes[0].at {
+                    return keyframes[0].value;
+                }
+                if time >= keyframes[keyframes.len() - 1].at {
+                    return keyframes[keyframes.len() - 1].value;
+                }
+                let next = keyframes.partition_point(|k| k.at <= time);
+                let (before, after) = (keyframes[next - 1], keyframes[next]);
+                let span = after.at - before.at;
+                let t = if span > 0.0 {
+                    (time - before.at) / span
+                } else {
+                    0.0
+                };
+                before.value + (after.value - before.value) * t
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fundsp::hacker::shared;
+
+    #[test]
+    fn interpolates_linearly_between_keyframes() {
+        let target = shared(0.0);
+        let mut lane = AutomationLane::new(target.clone());
+        lane.add_point(0.0, 0.0);
+        lane.add_point(2.0, 10.0);
+
+        lane.advance(1.0);
+        assert_eq!(target.value(), 5.0_f32);
+    }
+
+    #[test]
+    fn holds_the_boundary_value_outside_the_keyframe_range() {
+        let target = shared(0.0);
+        let mut lane = AutomationLane::new(target.clone());
+        lane.add_point(1.0, 1.0);
+        lane.add_point(2.0, 2.0);
+
+        lane.advance(0.0);
+        assert_eq!(target.value(), 1.0_f32);
+        lane.advance(5.0);
+        assert_eq!(target.value(), 2.0_f32);
+    }
+}
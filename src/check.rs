@@ -0,0 +1,39 @@
+//! `octmm check`: loads a project and runs its Lua script without opening
+//! an audio device, to catch syntax and obvious runtime errors in CI or
+//! before a live set.
+
+use std::path::PathBuf;
+
+use mlua::Lua;
+
+use crate::lua;
+use crate::project::Project;
+
+#[derive(Debug, clap::Args)]
+pub struct CheckArgs {
+    /// Project directory to validate.
+    pub project: PathBuf,
+}
+
+pub fn run(args: CheckArgs) -> anyhow::Result<()> {
+    let project = Project::load(&args.project)?;
+    let lua = Lua::new();
+
+    let nodes = Default::default();
+    let transport = Default::default();
+    let timer = Default::default();
+    let keys = Default::default();
+    lua::install(&lua, nodes, transport, timer, keys, Default::default())?;
+    lua::install_project(&lua, &project)?;
+
+    for module in project.modules()? {
+        let source = std::fs::read_to_string(&module)?;
+        lua::exec_file(&lua, &module, &source)?;
+    }
+
+    let source = std::fs::read_to_string(&project.entry_script)?;
+    lua::exec_file(&lua, &project.entry_script, &source)?;
+
+    println!("{}: OK", project.root.display());
+    Ok(())
+}
@@ -0,0 +1,86 @@
+//! Top-level command-line interface.
+
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+use log::LevelFilter;
+
+use crate::check::{self, CheckArgs};
+use crate::create::{self, CreateArgs};
+use crate::daemon::{self, DaemonArgs};
+use crate::export::{self, ExportArgs};
+use crate::play::{self, PlayArgs};
+use crate::repl::{self, ReplArgs};
+use crate::stats::{self, StatsArgs};
+
+#[derive(Parser)]
+#[command(name = "octmm", version, about = "Live-coding audio sequencer")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+
+    /// Also append every log line to this file, in addition to the
+    /// console.
+    #[arg(long, global = true)]
+    pub log_file: Option<PathBuf>,
+
+    /// Log `debug`-level messages too, not just `info` and above.
+    #[arg(long, global = true)]
+    pub verbose: bool,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Render a project's master bus to an audio file.
+    Export(ExportArgs),
+    /// Play a playlist of projects back to back, forever.
+    Daemon(DaemonArgs),
+    /// Validate a project's script without opening an audio device.
+    Check(CheckArgs),
+    /// Report how often a project's script calls into the OCTMM API.
+    Stats(StatsArgs),
+    /// Scaffold a new project directory.
+    Create(CreateArgs),
+    /// Interactive Lua console attached to a loaded project.
+    Repl(ReplArgs),
+    /// Load a project and keep it running.
+    Play(PlayArgs),
+}
+
+pub fn run() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    let level = if cli.verbose {
+        LevelFilter::Debug
+    } else {
+        LevelFilter::Info
+    };
+    crate::logging::init(level, cli.log_file.as_deref())?;
+
+    match cli.command {
+        Command::Export(args) => {
+            // TODO: replace with the actual rendered master bus (and, for
+            // `args.stems`, the actual per-bus renders) once the
+            // runner/project pipeline is wired up. That render should go
+            // through `audio::select_backend(true, &output)` (always the
+            // null backend — export has no business opening a real
+            // device) rather than opening its own cpal stream, and should
+            // use `song::poll_end_song`/`SongLength` plus, when
+            // `args.tail` is set, `song::is_silent` to decide when to
+            // stop rendering.
+            if args.stems {
+                let stems: Vec<(String, Vec<f32>)> = Vec::new();
+                export::export_stems(&stems, &args)
+            } else {
+                let samples: Vec<f32> = Vec::new();
+                export::export(&samples, &args)
+            }
+        }
+        Command::Daemon(args) => daemon::run(args),
+        Command::Check(args) => check::run(args),
+        Command::Stats(args) => stats::run(args),
+        Command::Create(args) => create::run(args),
+        Command::Repl(args) => repl::run(args),
+        Command::Play(args) => play::run(args),
+    }
+}
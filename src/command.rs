@@ -0,0 +1,82 @@
+//! A namespaced registry of the commands Lua scripts can issue (e.g.
+//! `"noise:white"`, `"filter:lowpass"`), with enough metadata attached to
+//! drive the `help()` Lua command.
+
+use std::collections::BTreeMap;
+
+/// One registered command: its namespaced name, a short description, and
+/// the argument names it expects, for display only.
+#[derive(Debug, Clone)]
+pub struct CommandInfo {
+    pub namespace: &'static str,
+    pub name: &'static str,
+    pub summary: &'static str,
+    pub args: &'static [&'static str],
+}
+
+impl CommandInfo {
+    pub fn qualified_name(&self) -> String {
+        format!("{}:{}", self.namespace, self.name)
+    }
+}
+
+#[derive(Default)]
+pub struct CommandRegistry {
+    commands: BTreeMap<String, CommandInfo>,
+}
+
+impl CommandRegistry {
+    pub fn register(&mut self, info: CommandInfo) {
+        self.commands.insert(info.qualified_name(), info);
+    }
+
+    pub fn get(&self, qualified_name: &str) -> Option<&CommandInfo> {
+        self.commands.get(qualified_name)
+    }
+
+    /// All commands in a namespace, e.g. everything under `"noise"`.
+    pub fn in_namespace(&self, namespace: &str) -> Vec<&CommandInfo> {
+        self.commands
+            .values()
+            .filter(|c| c.namespace == namespace)
+            .collect()
+    }
+
+    /// Formats the full command list the way `help()` prints it:
+    /// `namespace:name(args) - summary`, grouped by namespace.
+    pub fn help_text(&self) -> String {
+        let mut out = String::new();
+        let mut last_namespace = "";
+        for command in self.commands.values() {
+            if command.namespace != last_namespace {
+                out.push_str(&format!("\n[{}]\n", command.namespace));
+                last_namespace = command.namespace;
+            }
+            out.push_str(&format!(
+                "  {}({}) - {}\n",
+                command.qualified_name(),
+                command.args.join(", "),
+                command.summary
+            ));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn help_text_groups_by_namespace() {
+        let mut registry = CommandRegistry::default();
+        registry.register(CommandInfo {
+            namespace: "noise",
+            name: "white",
+            summary: "white noise generator",
+            args: &[],
+        });
+        assert!(registry.help_text().contains("[noise]"));
+        assert!(registry.help_text().contains("noise:white() - white noise generator"));
+    }
+}
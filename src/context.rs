@@ -0,0 +1,18 @@
+//! The per-tick context threaded through [`crate::runner::Module::update`].
+//!
+//! Started as a raw `&Lua` reference, but modules kept needing more than
+//! that (dt, and soon rate-limiting/command state) — wrapping it now means
+//! adding fields later doesn't change every `Module` impl's signature.
+
+use mlua::Lua;
+
+pub struct Context<'a> {
+    pub lua: &'a Lua,
+    pub dt: f64,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(lua: &'a Lua, dt: f64) -> Self {
+        Self { lua, dt }
+    }
+}
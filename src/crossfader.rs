@@ -0,0 +1,67 @@
+//! A continuous crossfader between two channels or scenes, using an
+//! equal-power curve so the perceived loudness stays constant across the
+//! sweep rather than dipping in the middle the way a linear fade would.
+
+use std::f64::consts::FRAC_PI_2;
+
+/// Position runs from `0.0` (fully channel A) to `1.0` (fully channel B).
+pub struct Crossfader {
+    position: f64,
+}
+
+impl Crossfader {
+    pub fn new() -> Self {
+        Self { position: 0.0 }
+    }
+
+    pub fn set_position(&mut self, position: f64) {
+        self.position = position.clamp(0.0, 1.0);
+    }
+
+    pub fn position(&self) -> f64 {
+        self.position
+    }
+
+    /// The equal-power gain for each side at the current position.
+    pub fn gains(&self) -> (f64, f64) {
+        let angle = self.position * FRAC_PI_2;
+        (angle.cos(), angle.sin())
+    }
+
+    /// Mixes `a` and `b` at the current position.
+    pub fn mix(&self, a: f64, b: f64) -> f64 {
+        let (gain_a, gain_b) = self.gains();
+        a * gain_a + b * gain_b
+    }
+}
+
+impl Default for Crossfader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fully_one_side_passes_it_through_unattenuated() {
+        let mut fader = Crossfader::new();
+        fader.set_position(0.0);
+        assert!((fader.mix(1.0, 1.0) - 1.0).abs() < 1e-9);
+
+        fader.set_position(1.0);
+        assert!((fader.mix(1.0, 1.0) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn midpoint_keeps_equal_power_not_equal_amplitude() {
+        let mut fader = Crossfader::new();
+        fader.set_position(0.5);
+        let (gain_a, gain_b) = fader.gains();
+        assert!((gain_a - gain_b).abs() < 1e-9);
+        // Equal-power: each side is attenuated to ~0.707, not 0.5.
+        assert!((gain_a - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,235 @@
+//! `octmm daemon --playlist <file>`: plays a sequence of projects back to
+//! back, for kiosk/installation/radio deployments that need to run
+//! unattended for weeks. The playlist file is watched and reloaded on
+//! change so it can be edited live.
+//!
+//! Between projects, the ending one keeps running for the `--crossfade`
+//! length and is then cut; the audio of the two projects is not blended
+//! (see [`play_with_crossfade`]).
+
+use std::cell::RefCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::audio::{self, AudioModule};
+use crate::health::Watchdog;
+use crate::keys::KeysModule;
+use crate::lua;
+use crate::output::{OutputArgs, OutputConfig};
+use crate::project::Project;
+use crate::runner::Runner;
+use crate::song::{self, SongLength};
+use crate::timer::TimerModule;
+use crate::transport::Transport;
+
+#[derive(Debug, clap::Args)]
+pub struct DaemonArgs {
+    /// Text file with one project directory per line.
+    #[arg(long)]
+    pub playlist: PathBuf,
+
+    /// Crossfade length between consecutive projects, in seconds.
+    #[arg(long, default_value_t = 2.0)]
+    pub crossfade: f64,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+struct Playlist {
+    path: PathBuf,
+    entries: Vec<PathBuf>,
+    last_modified: SystemTime,
+}
+
+impl Playlist {
+    fn load(path: &Path) -> anyhow::Result<Self> {
+        let entries = parse_playlist(path)?;
+        let last_modified = fs::metadata(path)?.modified()?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            entries,
+            last_modified,
+        })
+    }
+
+    fn reload_if_changed(&mut self) -> anyhow::Result<bool> {
+        let modified = fs::metadata(&self.path)?.modified()?;
+        if modified != self.last_modified {
+            self.entries = parse_playlist(&self.path)?;
+            self.last_modified = modified;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
+
+fn parse_playlist(path: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let text = fs::read_to_string(path)?;
+    Ok(text
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(PathBuf::from)
+        .collect())
+}
+
+/// Runs the playlist forever, reloading it between tracks when the file
+/// on disk has changed.
+pub fn run(args: DaemonArgs) -> anyhow::Result<()> {
+    let mut playlist = Playlist::load(&args.playlist)?;
+    if playlist.entries.is_empty() {
+        anyhow::bail!("playlist {} has no entries", args.playlist.display());
+    }
+
+    let mut watchdog = Watchdog::start()?;
+    let mut index = 0;
+    loop {
+        playlist.reload_if_changed()?;
+        let entry = &playlist.entries[index % playlist.entries.len()];
+        let project = Project::load(entry)?;
+        let output = OutputConfig::from_args(&args.output, project.output_overrides()?);
+        play_with_crossfade(&project, args.crossfade, &output, &mut watchdog)?;
+        index += 1;
+    }
+}
+
+/// The outgoing/incoming gain a crossfade should be at `elapsed_secs`
+/// into a fade of length `crossfade_secs` — linear, summing to `1.0`
+/// throughout, unlike the equal-power curve of
+/// [`crate::crossfader::Crossfader`].
+///
+/// Nothing here multiplies real samples by these yet: nothing in the
+/// engine renders a project's master bus to samples at all yet (the
+/// same gap `Command::Export` is stuck on — see its TODO), so there is
+/// no second project's audio to blend into. [`play_with_crossfade`]
+/// still uses this to decide *when* the fade is done and it's safe to
+/// cut to the next track, which is the real, usable part of a crossfade
+/// today; multiplying and summing two rendered buffers instead of
+/// cutting is a small step from here once that rendering exists.
+fn crossfade_gains(elapsed_secs: f64, crossfade_secs: f64) -> (f64, f64) {
+    if crossfade_secs <= 0.0 {
+        return (0.0, 1.0);
+    }
+    let t = (elapsed_secs / crossfade_secs).clamp(0.0, 1.0);
+    (1.0 - t, t)
+}
+
+/// Plays `project` until it signals it's done — either `Song.SetLength`
+/// or a `_G.EndSong` function (see [`crate::song`]) — ticking a real
+/// [`Runner`] paced to wall-clock time, then keeps it running for up to
+/// `crossfade_secs` more (per [`crossfade_gains`]) before handing back
+/// to the playlist loop for the next entry, instead of cutting instantly.
+///
+/// Opens a real output device through [`AudioModule`] for the
+/// project's duration. The callback it hands that module only fills
+/// silence — there's nothing else to give it until the node-registry
+/// pipeline can render a master bus (again, `Command::Export`'s TODO).
+fn play_with_crossfade(
+    project: &Project,
+    crossfade_secs: f64,
+    output: &OutputConfig,
+    watchdog: &mut Watchdog,
+) -> anyhow::Result<()> {
+    let lua = mlua::Lua::new();
+
+    let nodes = Default::default();
+    let transport = Rc::new(RefCell::new(Transport::default()));
+    let timer = Rc::new(RefCell::new(TimerModule::default()));
+    let keys = Rc::new(RefCell::new(KeysModule::default()));
+    let song_length: SongLength = Default::default();
+    // `keys` is handed to Lua so a script's `Keys.OnPress` calls don't
+    // error out, but it's never added as a `Runner` module below — an
+    // unattended daemon has no terminal to put into raw mode, so those
+    // callbacks just never fire here, same as they wouldn't under
+    // `octmm check`.
+    lua::install(
+        &lua,
+        nodes,
+        transport.clone(),
+        timer.clone(),
+        keys,
+        song_length.clone(),
+    )?;
+    lua::install_project(&lua, project)?;
+
+    for module in project.modules()? {
+        let source = fs::read_to_string(&module)?;
+        lua::exec_file(&lua, &module, &source)?;
+    }
+    let source = fs::read_to_string(&project.entry_script)?;
+    lua::exec_file(&lua, &project.entry_script, &source)?;
+
+    let sample_rate = output.sample_rate.unwrap_or(48_000);
+    let backend = audio::select_backend(false, output);
+    let mut audio_module = AudioModule::init(
+        backend,
+        sample_rate,
+        2,
+        Box::new(|data: &mut [f32]| {
+            // TODO: fill from the project's actual rendered master bus
+            // once the node-registry/mixer pipeline produces one.
+            data.fill(0.0);
+        }),
+    )?;
+
+    let tick_rate = 1000.0;
+    let sleep_duration = Duration::from_secs_f64(1.0 / tick_rate);
+    let mut runner = Runner::builder()
+        .with_lua(lua)
+        .with_module(Box::new(timer.clone()))
+        .build();
+
+    let mut fade_started: Option<Instant> = None;
+    loop {
+        runner.tick()?;
+        let stats = runner.jitter_stats();
+        transport.borrow_mut().advance(stats.last_dt);
+        watchdog.tick()?;
+
+        let song_finished = song::poll_end_song(runner.lua())?
+            || song_length
+                .get()
+                .is_some_and(|length_beats| timer.borrow().elapsed_beats() >= length_beats);
+
+        if song_finished {
+            let started = *fade_started.get_or_insert_with(Instant::now);
+            let (outgoing, _incoming) =
+                crossfade_gains(started.elapsed().as_secs_f64(), crossfade_secs);
+            if outgoing <= 0.0 {
+                break;
+            }
+        }
+
+        std::thread::sleep(sleep_duration);
+    }
+
+    audio_module.stop();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gains_sum_to_one_throughout_the_fade() {
+        for elapsed in [0.0, 0.5, 1.0, 1.5, 2.0] {
+            let (outgoing, incoming) = crossfade_gains(elapsed, 2.0);
+            assert!((outgoing + incoming - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn starts_fully_outgoing_and_ends_fully_incoming() {
+        assert_eq!(crossfade_gains(0.0, 2.0), (1.0, 0.0));
+        assert_eq!(crossfade_gains(2.0, 2.0), (0.0, 1.0));
+    }
+
+    #[test]
+    fn a_zero_length_crossfade_is_an_instant_cut() {
+        assert_eq!(crossfade_gains(0.0, 0.0), (0.0, 1.0));
+    }
+}
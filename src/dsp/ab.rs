@@ -0,0 +1,77 @@
+//! A/B comparison between two patches, for quickly flipping back and
+//! forth while tuning a sound without losing either version.
+
+use super::DspModule;
+
+/// Slot selector for [`PatchAb`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slot {
+    A,
+    B,
+}
+
+pub struct PatchAb {
+    a: DspModule,
+    b: DspModule,
+    active: Slot,
+}
+
+impl PatchAb {
+    pub fn new(a: DspModule, b: DspModule) -> Self {
+        Self {
+            a,
+            b,
+            active: Slot::A,
+        }
+    }
+
+    pub fn active_slot(&self) -> Slot {
+        self.active
+    }
+
+    pub fn switch_to(&mut self, slot: Slot) {
+        self.active = slot;
+    }
+
+    pub fn toggle(&mut self) {
+        self.active = match self.active {
+            Slot::A => Slot::B,
+            Slot::B => Slot::A,
+        };
+    }
+
+    /// Replaces the patch in the slot that currently isn't active, so
+    /// tweaking it doesn't disturb what's playing.
+    pub fn replace_inactive(&mut self, module: DspModule) {
+        match self.active {
+            Slot::A => self.b = module,
+            Slot::B => self.a = module,
+        }
+    }
+
+    pub fn tick(&mut self) -> f64 {
+        match self.active {
+            Slot::A => self.a.tick(),
+            Slot::B => self.b.tick(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dsp::NodeType;
+
+    #[test]
+    fn toggle_flips_between_slots() {
+        let mut ab = PatchAb::new(
+            DspModule::build(&NodeType::Sine { freq: 440.0 }).unwrap(),
+            DspModule::build(&NodeType::Saw { freq: 220.0 }).unwrap(),
+        );
+        assert_eq!(ab.active_slot(), Slot::A);
+        ab.toggle();
+        assert_eq!(ab.active_slot(), Slot::B);
+        ab.toggle();
+        assert_eq!(ab.active_slot(), Slot::A);
+    }
+}
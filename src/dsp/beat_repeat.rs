@@ -0,0 +1,79 @@
+//! Beat-repeat/stutter: continuously captures the last beat's worth of
+//! audio into a ring buffer, and on trigger freezes it and loops that
+//! captured beat until released — the classic "glitch" effect.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Passthrough,
+    /// Looping the captured beat, reading from this offset into `ring`.
+    Repeating(usize),
+}
+
+pub struct BeatRepeat {
+    ring: Vec<f64>,
+    write_pos: usize,
+    state: State,
+}
+
+impl BeatRepeat {
+    /// `capture_len` is the ring buffer length in samples — typically one
+    /// beat (or a subdivision of one) at the project's tempo.
+    pub fn new(capture_len: usize) -> Self {
+        assert!(capture_len > 0, "beat-repeat needs a non-zero capture length");
+        Self {
+            ring: vec![0.0; capture_len],
+            write_pos: 0,
+            state: State::Passthrough,
+        }
+    }
+
+    /// Freezes the current capture and starts looping it. Recording
+    /// pauses until [`BeatRepeat::release`].
+    pub fn trigger(&mut self) {
+        self.state = State::Repeating(self.write_pos);
+    }
+
+    /// Stops looping and resumes normal passthrough/capture.
+    pub fn release(&mut self) {
+        self.state = State::Passthrough;
+    }
+
+    pub fn is_repeating(&self) -> bool {
+        matches!(self.state, State::Repeating(_))
+    }
+
+    pub fn tick(&mut self, input: f64) -> f64 {
+        match self.state {
+            State::Passthrough => {
+                self.ring[self.write_pos] = input;
+                self.write_pos = (self.write_pos + 1) % self.ring.len();
+                input
+            }
+            State::Repeating(pos) => {
+                let sample = self.ring[pos];
+                self.state = State::Repeating((pos + 1) % self.ring.len());
+                sample
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loops_the_captured_beat_until_released() {
+        let mut fx = BeatRepeat::new(3);
+        for sample in [1.0, 2.0, 3.0] {
+            assert_eq!(fx.tick(sample), sample); // passthrough while capturing
+        }
+        fx.trigger();
+        let repeated: Vec<f64> = (0..6).map(|_| fx.tick(0.0)).collect();
+        assert_eq!(repeated, vec![1.0, 2.0, 3.0, 1.0, 2.0, 3.0]);
+
+        fx.release();
+        assert_eq!(fx.tick(9.0), 9.0);
+        assert!(!fx.is_repeating());
+    }
+}
@@ -0,0 +1,37 @@
+//! Test tones for level calibration and speaker checks. Levels are kept
+//! well below full scale by default, in keeping with the output cap in
+//! [`crate::output::SafetyLimiter`] — these are meant to be played
+//! through monitors at a sane volume, not as a way to find your system's
+//! clipping point.
+
+use fundsp::hacker::*;
+
+/// Broadcast calibration standard: 1 kHz at -18 dBFS.
+pub const CALIBRATION_FREQ_HZ: f64 = 1000.0;
+pub const CALIBRATION_LEVEL: f64 = 0.125; // roughly -18 dBFS
+
+/// A steady calibration tone at [`CALIBRATION_FREQ_HZ`] and
+/// [`CALIBRATION_LEVEL`], for setting monitor levels before a session.
+pub fn reference_tone() -> An<impl AudioNode> {
+    sine_hz(CALIBRATION_FREQ_HZ as f32) * CALIBRATION_LEVEL as f32
+}
+
+/// A logarithmic sweep from `start_hz` to `end_hz` over `duration_secs`,
+/// for checking speaker or room frequency response. Amplitude is held at
+/// [`CALIBRATION_LEVEL`] throughout.
+pub fn sweep_tone(
+    start_hz: f64,
+    end_hz: f64,
+    duration_secs: f64,
+) -> An<impl AudioNode> {
+    let ratio = (end_hz / start_hz).max(1e-6);
+    let rate = ratio.ln() / duration_secs.max(1e-6);
+    let freq = envelope(move |t| start_hz * (rate * t).exp());
+    (freq >> sine()) * CALIBRATION_LEVEL as f32
+}
+
+/// Pink noise at [`CALIBRATION_LEVEL`], useful for checking a room's
+/// frequency balance since pink noise has equal energy per octave.
+pub fn calibration_noise() -> An<impl AudioNode> {
+    pink() * CALIBRATION_LEVEL as f32
+}
@@ -0,0 +1,62 @@
+//! Chip-style oscillators modeled after classic sound chips: quantized
+//! pulse waves with selectable duty cycle (NES 2A03 / Game Boy APU), and
+//! LFSR-driven pseudo-noise (2A03's noise channel).
+
+use fundsp::hacker::*;
+
+/// A pulse wave at `freq` Hz with `duty` (0.0-1.0) fraction of the cycle
+/// high, like the 2A03/Game Boy pulse channels' selectable duty cycles
+/// (typically 12.5%, 25%, 50%, 75%).
+pub fn pulse(freq: f64, duty: f32) -> An<impl AudioNode> {
+    saw_hz(freq as f32) >> map(move |i: &Frame<f32, U1>| {
+        let phase = (i[0] * 0.5 + 0.5).fract();
+        if phase < duty {
+            1.0
+        } else {
+            -1.0
+        }
+    })
+}
+
+/// 2A03-style noise: a linear feedback shift register clocked at
+/// `clock_hz`, tapped for a pseudo-random bitstream and converted to
+/// +-1.0 samples. `short_mode` uses a 6-bit tap (metallic, higher-pitched
+/// noise) instead of the default 1-bit tap.
+pub struct Lfsr {
+    register: u16,
+    short_mode: bool,
+    clock_hz: f64,
+    sample_rate: f64,
+    phase: f64,
+    output: f32,
+}
+
+impl Lfsr {
+    pub fn new(clock_hz: f64, sample_rate: f64, short_mode: bool) -> Self {
+        Self {
+            register: 1,
+            short_mode,
+            clock_hz,
+            sample_rate,
+            phase: 0.0,
+            output: 1.0,
+        }
+    }
+
+    pub fn tick(&mut self) -> f32 {
+        self.phase += self.clock_hz / self.sample_rate;
+        while self.phase >= 1.0 {
+            self.phase -= 1.0;
+            self.step();
+        }
+        self.output
+    }
+
+    fn step(&mut self) {
+        let tap_bit = if self.short_mode { 6 } else { 1 };
+        let feedback = (self.register & 1) ^ ((self.register >> tap_bit) & 1);
+        self.register >>= 1;
+        self.register |= feedback << 14;
+        self.output = if self.register & 1 == 1 { 1.0 } else { -1.0 };
+    }
+}
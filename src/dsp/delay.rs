@@ -0,0 +1,37 @@
+//! A feedback delay line for echo effects. Built on the same comb filter
+//! as [`super::reverb`], but exposed as its own chainable effect with a
+//! dry/wet mix rather than being summed into a reverb tank.
+
+use fundsp::hacker::*;
+
+use super::reverb::comb;
+
+/// `time_secs` between repeats, `feedback` gain on each repeat, `mix`
+/// blending the echoed signal back in with the dry input.
+pub fn delay_effect(time_secs: f64, feedback: f64, mix: f64) -> An<impl AudioNode<Inputs = U1, Outputs = U1>> {
+    let mix = mix.clamp(0.0, 1.0) as f32;
+    (pass() * (1.0 - mix)) & (comb(time_secs, feedback) * mix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_mix_is_pure_dry_passthrough() {
+        let mut fx = delay_effect(0.01, 0.5, 0.0);
+        fx.set_sample_rate(1000.0);
+        assert_eq!(fx.filter_mono(1.0), 1.0);
+        assert_eq!(fx.filter_mono(0.5), 0.5);
+    }
+
+    #[test]
+    fn nonzero_mix_echoes_an_impulse_after_the_delay() {
+        let mut fx = delay_effect(0.01, 0.5, 0.5);
+        fx.set_sample_rate(1000.0);
+        let delay_samples = (0.01 * 1000.0).round() as usize;
+        let mut out = vec![fx.filter_mono(1.0)];
+        out.extend((0..delay_samples + 5).map(|_| fx.filter_mono(0.0)));
+        assert!(out[delay_samples].abs() > 0.0);
+    }
+}
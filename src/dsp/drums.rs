@@ -0,0 +1,36 @@
+//! Classic synthesized drum models: a pitch-swept kick, a noise+tone
+//! snare, and a filtered-noise hat. Each is a self-contained one-shot
+//! graph, not a node that needs a separate trigger/envelope wired in.
+
+use fundsp::hacker::*;
+
+/// A sine that sweeps from `start_freq` down to `end_freq` over `decay`
+/// seconds, amplitude-enveloped to the same length.
+pub fn kick(start_freq: f64, end_freq: f64, decay: f64) -> Box<dyn AudioUnit> {
+    let sweep = envelope(move |t| {
+        let t = (t / decay).min(1.0);
+        lerp(start_freq, end_freq, t)
+    });
+    let amp = envelope(move |t| 1.0 - (t / decay).min(1.0));
+    Box::new((sweep >> sine()) * amp)
+}
+
+/// A tonal component (typically ~180 Hz) mixed with filtered noise,
+/// both decaying over `decay` seconds.
+pub fn snare(tone_freq: f64, decay: f64) -> Box<dyn AudioUnit> {
+    let amp = envelope(move |t| 1.0 - (t / decay).min(1.0));
+    let tone = sine_hz(tone_freq as f32) * amp.clone();
+    let noisy = (noise() >> highpass_hz(1000.0, 1.0)) * amp;
+    Box::new(tone * 0.5 + noisy * 0.5)
+}
+
+/// High-pass filtered noise with a fast decay, for a closed hat; pass a
+/// longer `decay` for an open hat.
+pub fn hat(decay: f64) -> Box<dyn AudioUnit> {
+    let amp = envelope(move |t| 1.0 - (t / decay).min(1.0));
+    Box::new((noise() >> highpass_hz(7000.0, 1.0)) * amp)
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
@@ -0,0 +1,49 @@
+//! ADSR amplitude envelopes, driven by a gate signal rather than Rust-side
+//! timers so note-on/note-off stay sample-accurate.
+
+use fundsp::hacker::*;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Adsr {
+    pub attack: f64,
+    pub decay: f64,
+    pub sustain: f64,
+    pub release: f64,
+}
+
+impl Adsr {
+    /// Builds the envelope generator. `gate` should be driven to `1.0` on
+    /// note-on and `0.0` on note-off by the caller.
+    pub fn generator(&self) -> An<impl AudioNode<Inputs = U1, Outputs = U1>> {
+        adsr_live(
+            self.attack as f32,
+            self.decay as f32,
+            self.sustain as f32,
+            self.release as f32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rises_during_attack_and_settles_at_sustain() {
+        let adsr = Adsr {
+            attack: 0.01,
+            decay: 0.01,
+            sustain: 0.5,
+            release: 0.01,
+        };
+        let mut gen = adsr.generator();
+        gen.set_sample_rate(1000.0);
+        // Hold the gate open for well past attack+decay; output should
+        // settle near the sustain level.
+        let mut last = 0.0;
+        for _ in 0..100 {
+            last = gen.filter_mono(1.0);
+        }
+        assert!((last - adsr.sustain as f32).abs() < 0.05);
+    }
+}
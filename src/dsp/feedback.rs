@@ -0,0 +1,34 @@
+//! General-purpose feedback loops for the DSP DSL: routing a node's
+//! output back into its own input, scaled down to stay stable. The comb
+//! filter in [`super::reverb`] is one specific case of this (a delay
+//! fed back on itself); this is the same building block generalized to
+//! any forward node, for FM feedback, screech loops, and similar.
+
+use fundsp::hacker::*;
+
+/// Feeds `forward`'s output back into its input, scaled by
+/// `feedback_gain`. `fundsp`'s feedback node implicitly delays the loop
+/// by one sample, since a truly zero-delay cycle isn't computable.
+pub fn feedback_loop<X>(forward: An<X>, feedback_gain: f64) -> An<impl AudioNode<Inputs = U1, Outputs = U1>>
+where
+    X: AudioNode<Inputs = U1, Outputs = U1> + 'static,
+{
+    feedback2(forward, mul(feedback_gain as f32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feedback_loop_decays_with_gain_below_one() {
+        let mut node = feedback_loop(pass(), 0.5);
+        node.set_sample_rate(1000.0);
+        let mut out = vec![node.filter_mono(1.0)];
+        out.extend((0..20).map(|_| node.filter_mono(0.0)));
+        // With the loop delayed by one sample and scaled by the feedback
+        // gain each time around, the tail should shrink towards zero.
+        assert!(out[1].abs() >= out[10].abs());
+        assert!(out.iter().all(|s| s.is_finite()));
+    }
+}
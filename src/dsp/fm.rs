@@ -0,0 +1,25 @@
+//! A small multi-operator FM synthesis building block, in the style of
+//! classic FM chips (OPL/OPN): operators are sine oscillators frequency-
+//! modulated by each other per a fixed algorithm, rather than the
+//! general-purpose [`super::sync::phase_modulate`] pair.
+
+use fundsp::hacker::*;
+
+/// A single FM operator: a sine oscillator at `ratio * base_freq`,
+/// scaled by `level`.
+#[derive(Debug, Clone, Copy)]
+pub struct Operator {
+    pub ratio: f64,
+    pub level: f64,
+}
+
+/// Two-operator FM: operator 1 modulates operator 2's frequency, and
+/// operator 2 is the audible output. This is the classic "algorithm 0"
+/// FM patch (a single modulator/carrier pair).
+pub fn two_op(base_freq: f64, modulator: Operator, carrier: Operator) -> Box<dyn AudioUnit> {
+    let mod_signal = sine_hz((base_freq * modulator.ratio) as f32) * (modulator.level as f32);
+    let carrier_freq = base_freq * carrier.ratio;
+    Box::new(
+        ((mod_signal + carrier_freq as f32) >> sine()) * (carrier.level as f32),
+    )
+}
@@ -0,0 +1,91 @@
+//! Freeze/hold: sustains the current moment of sound indefinitely.
+//!
+//! This is a time-domain granular freeze, not a true FFT spectral
+//! freeze — it loops the captured grain through two overlapping,
+//! triangle-windowed playheads a half-grain apart, which overlap-add
+//! back to a constant amplitude and hides the loop seam well enough for
+//! most material. A real spectral freeze (holding the magnitude
+//! spectrum while letting phase keep evolving) would need an FFT
+//! pipeline this crate doesn't have yet.
+
+pub struct SpectralFreeze {
+    ring: Vec<f64>,
+    write_pos: usize,
+    frozen: bool,
+    phase: f64,
+}
+
+impl SpectralFreeze {
+    /// `grain_len` is the frozen grain's length in samples.
+    pub fn new(grain_len: usize) -> Self {
+        assert!(grain_len > 1, "freeze needs a grain of at least 2 samples");
+        Self {
+            ring: vec![0.0; grain_len],
+            write_pos: 0,
+            frozen: false,
+            phase: 0.0,
+        }
+    }
+
+    pub fn set_frozen(&mut self, frozen: bool) {
+        if frozen && !self.frozen {
+            self.phase = 0.0;
+        }
+        self.frozen = frozen;
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    pub fn tick(&mut self, input: f64) -> f64 {
+        if !self.frozen {
+            self.ring[self.write_pos] = input;
+            self.write_pos = (self.write_pos + 1) % self.ring.len();
+            return input;
+        }
+
+        let len = self.ring.len() as f64;
+        let head_a = self.phase;
+        let head_b = (self.phase + len / 2.0) % len;
+        // Playhead positions count from the oldest sample (at `write_pos`),
+        // so the seam between newest and oldest falls where the window is 0.
+        let ring_len = self.ring.len();
+        let sample = self.ring[(self.write_pos + head_a as usize) % ring_len]
+            * triangle_window(head_a, len)
+            + self.ring[(self.write_pos + head_b as usize) % ring_len] * triangle_window(head_b, len);
+        self.phase = (self.phase + 1.0) % len;
+        sample
+    }
+}
+
+/// A triangle window peaking at the grain's midpoint; two copies offset
+/// by half a grain overlap-add to a flat amplitude of 1.
+fn triangle_window(pos: f64, len: f64) -> f64 {
+    let t = pos / len;
+    1.0 - (2.0 * t - 1.0).abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passthrough_while_not_frozen() {
+        let mut fx = SpectralFreeze::new(4);
+        assert_eq!(fx.tick(1.0), 1.0);
+        assert_eq!(fx.tick(2.0), 2.0);
+    }
+
+    #[test]
+    fn freezing_keeps_producing_sound_after_input_stops() {
+        let mut fx = SpectralFreeze::new(8);
+        for sample in [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0] {
+            fx.tick(sample);
+        }
+        fx.set_frozen(true);
+        let output: Vec<f64> = (0..8).map(|_| fx.tick(0.0)).collect();
+        assert!(output.iter().all(|s| s.is_finite()));
+        assert!(output.iter().any(|&s| s.abs() > 0.0));
+    }
+}
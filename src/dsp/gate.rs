@@ -0,0 +1,71 @@
+//! A noise gate: mutes the signal once its envelope drops below a
+//! threshold for longer than `hold`, to cut room noise between phrases.
+//! Also useful on its own for silence detection.
+
+pub struct NoiseGate {
+    threshold: f64,
+    hold_samples: u32,
+    sample_rate: f64,
+    envelope: f64,
+    /// How many consecutive samples the envelope has been below threshold.
+    below_count: u32,
+    open: bool,
+}
+
+impl NoiseGate {
+    pub fn new(threshold: f64, hold_secs: f64, sample_rate: f64) -> Self {
+        Self {
+            threshold,
+            hold_samples: (hold_secs * sample_rate) as u32,
+            sample_rate,
+            envelope: 0.0,
+            below_count: 0,
+            open: true,
+        }
+    }
+
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+
+    /// `true` once the gate has been below threshold for at least `hold`.
+    pub fn is_silent(&self) -> bool {
+        !self.open
+    }
+
+    pub fn process(&mut self, input: f64) -> f64 {
+        const ENVELOPE_SMOOTHING: f64 = 0.01;
+        self.envelope += ENVELOPE_SMOOTHING * (input.abs() - self.envelope);
+
+        if self.envelope < self.threshold {
+            self.below_count = self.below_count.saturating_add(1);
+            if self.below_count >= self.hold_samples {
+                self.open = false;
+            }
+        } else {
+            self.below_count = 0;
+            self.open = true;
+        }
+
+        if self.open {
+            input
+        } else {
+            0.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closes_after_hold_time_of_silence() {
+        let mut gate = NoiseGate::new(0.01, 0.0, 1000.0);
+        assert!(!gate.is_silent());
+        for _ in 0..10 {
+            gate.process(0.0);
+        }
+        assert!(gate.is_silent());
+    }
+}
@@ -0,0 +1,222 @@
+//! Audio graph construction: turning a [`NodeType`] description into a
+//! running `fundsp` net that the runner can pull samples from.
+
+pub mod envelope;
+#[cfg(feature = "faust")]
+pub mod faust;
+pub mod ab;
+pub mod beat_repeat;
+pub mod calibration;
+pub mod chip;
+pub mod chorus;
+pub mod control;
+pub mod delay;
+pub mod drums;
+pub mod feedback;
+pub mod fm;
+pub mod freeze;
+pub mod sid;
+pub mod gate;
+pub mod looper;
+pub mod physical;
+pub mod polyblep;
+pub mod reverb;
+pub mod supersaw;
+pub mod sync;
+
+use fundsp::hacker::*;
+use fundsp::net::Net;
+
+pub use envelope::Adsr;
+pub use reverb::ReverbDesign;
+
+/// The kinds of signal sources a patch can instantiate.
+///
+/// This is intentionally a flat enum rather than a trait-object registry:
+/// nodes are cheap to add here and the match in [`DspModule::build`] is the
+/// single place that knows how to turn a description into a `fundsp` graph.
+#[derive(Debug, Clone)]
+pub enum NodeType {
+    Sine { freq: f64 },
+    Saw { freq: f64 },
+    Square { freq: f64 },
+    Triangle { freq: f64 },
+    Noise(NoiseType),
+    /// A single metronome click — a deep, longer `drums::kick` on the
+    /// downbeat (`accent`), a short `drums::hat` otherwise, so the two
+    /// are easy to tell apart by ear.
+    MetronomeClick { accent: bool },
+    /// A node for the Faust source file at the given path. Only available
+    /// with the `faust` feature. [`faust::load`] does not compile the
+    /// source, so this node is silent.
+    #[cfg(feature = "faust")]
+    Faust { source: std::path::PathBuf },
+}
+
+/// Noise/texture generators, for percussion and texture synthesis without
+/// samples.
+#[derive(Debug, Clone, Copy)]
+pub enum NoiseType {
+    White,
+    Pink,
+    Brown,
+    /// A single-sample impulse repeated at `freq` Hz.
+    Impulse { freq: f64 },
+}
+
+/// Filters that can be appended after any existing node, shared between
+/// [`DspModule::apply_filter`] and the Lua `NodeHandle` filter methods
+/// (`node:lowpass(...)` and friends).
+#[derive(Debug, Clone, Copy)]
+pub enum FilterType {
+    Lowpass { cutoff: f64, q: f64 },
+    Highpass { cutoff: f64, q: f64 },
+    Bandpass { cutoff: f64, q: f64 },
+    Notch { cutoff: f64, q: f64 },
+    Moog { cutoff: f64, q: f64 },
+}
+
+/// Waveshapers that can be appended after any existing node, for
+/// distortion/timbral shaping beyond what a filter alone can do.
+#[derive(Debug, Clone, Copy)]
+pub enum ShaperType {
+    /// Chebyshev polynomial of the given order, producing harmonics at
+    /// exactly that multiple of the input frequency.
+    Chebyshev { order: u32 },
+    /// Wavefolds the signal back on itself once it exceeds `threshold`.
+    Wavefold { threshold: f64 },
+}
+
+/// Owns the live `fundsp` graph for a patch and knows how to rebuild it
+/// from a [`NodeType`] description.
+///
+/// Stored as a [`Net`] rather than a bare `Box<dyn AudioUnit>`: every
+/// `apply_*` method below rebuilds the graph by composing the existing
+/// one with a new stage via `>>`/`*`, and `fundsp` only implements those
+/// operators for `Net`/`An<X>`, not for a boxed trait object on its own.
+pub struct DspModule {
+    graph: Net,
+}
+
+impl DspModule {
+    pub fn build(node: &NodeType) -> anyhow::Result<Self> {
+        let graph: Box<dyn AudioUnit> = match node {
+            NodeType::Sine { freq } => Box::new(sine_hz(*freq as f32)),
+            NodeType::Saw { freq } => Box::new(saw_hz(*freq as f32)),
+            NodeType::Square { freq } => Box::new(square_hz(*freq as f32)),
+            NodeType::Triangle { freq } => Box::new(triangle_hz(*freq as f32)),
+            NodeType::Noise(NoiseType::White) => Box::new(noise()),
+            NodeType::Noise(NoiseType::Pink) => Box::new(pink()),
+            NodeType::Noise(NoiseType::Brown) => Box::new(brown()),
+            NodeType::Noise(NoiseType::Impulse { freq }) => {
+                Box::new(noise() >> follow(0.0) >> (pass() * square_hz(*freq as f32)))
+            }
+            NodeType::MetronomeClick { accent: true } => drums::kick(1200.0, 200.0, 0.05),
+            NodeType::MetronomeClick { accent: false } => drums::hat(0.03),
+            #[cfg(feature = "faust")]
+            NodeType::Faust { source } => Box::new(faust::load(source)?),
+        };
+        Ok(Self {
+            graph: Net::wrap(graph),
+        })
+    }
+
+    pub fn tick(&mut self) -> f64 {
+        self.graph.get_mono() as f64
+    }
+
+    /// Reads one stereo frame. Only meaningful once the graph has been
+    /// widened to two channels, e.g. via [`DspModule::apply_pan`].
+    pub fn tick_stereo(&mut self) -> (f64, f64) {
+        let (left, right) = self.graph.get_stereo();
+        (left as f64, right as f64)
+    }
+
+    /// Rebuilds this node as itself panned across the stereo field.
+    /// `position` ranges from `-1.0` (hard left) to `1.0` (hard right).
+    pub fn apply_pan(self, position: f64) -> Self {
+        let graph = self.graph >> pan(position as f32);
+        Self { graph }
+    }
+
+    /// Rebuilds this node as itself followed by `filter`. Used by the Lua
+    /// `NodeHandle` filter methods to chain a filter onto an existing node.
+    pub fn apply_filter(self, filter: FilterType) -> Self {
+        let graph = match filter {
+            FilterType::Lowpass { cutoff, q } => self.graph >> lowpass_hz(cutoff as f32, q as f32),
+            FilterType::Highpass { cutoff, q } => {
+                self.graph >> highpass_hz(cutoff as f32, q as f32)
+            }
+            FilterType::Bandpass { cutoff, q } => {
+                self.graph >> bandpass_hz(cutoff as f32, q as f32)
+            }
+            FilterType::Notch { cutoff, q } => self.graph >> notch_hz(cutoff as f32, q as f32),
+            FilterType::Moog { cutoff, q } => self.graph >> moog_hz(cutoff as f32, q as f32),
+        };
+        Self { graph }
+    }
+
+    /// Rebuilds this node as itself shaped by an ADSR envelope, triggered
+    /// at construction time. Per-note retriggering belongs to whatever
+    /// plays notes against this node (see the instrument abstraction),
+    /// not to the node itself.
+    pub fn apply_adsr(self, adsr: Adsr) -> Self {
+        let gate = constant(1.0);
+        let graph = self.graph * (gate >> adsr.generator());
+        Self { graph }
+    }
+
+    /// Rebuilds this node as itself run through a feedback delay line.
+    pub fn apply_delay(self, time_secs: f64, feedback: f64, mix: f64) -> Self {
+        let graph = self.graph >> delay::delay_effect(time_secs, feedback, mix);
+        Self { graph }
+    }
+
+    /// Rebuilds this node as itself run through a modulated-delay chorus.
+    pub fn apply_chorus(self, seed: i64, separation: f64, variation: f64, rate: f64) -> Self {
+        let graph = self.graph >> chorus::chorus_effect(seed, separation, variation, rate);
+        Self { graph }
+    }
+
+    /// Rebuilds this node as itself fed into a Schroeder reverb tank.
+    pub fn apply_reverb(self, design: &ReverbDesign) -> Self {
+        let graph = self.graph >> Net::wrap(design.build());
+        Self { graph }
+    }
+
+    /// Rebuilds this node as itself run through a waveshaper, for
+    /// distortion/harmonic enrichment of an oscillator or sample.
+    pub fn apply_shaper(self, shaper: ShaperType) -> Self {
+        let graph = match shaper {
+            ShaperType::Chebyshev { order } => {
+                self.graph >> shape_fn(move |x| chebyshev(order, x))
+            }
+            ShaperType::Wavefold { threshold } => {
+                self.graph >> shape_fn(move |x| wavefold(x, threshold))
+            }
+        };
+        Self { graph }
+    }
+}
+
+/// Evaluates the Chebyshev polynomial of the first kind, `T_n(x)`, via the
+/// standard `cos(n * acos(x))` identity (valid for `x` clamped to
+/// `[-1, 1]`, which audio signals already are).
+fn chebyshev(order: u32, x: f32) -> f32 {
+    (order as f32 * x.clamp(-1.0, 1.0).acos()).cos()
+}
+
+/// Reflects `x` back into range whenever it exceeds `threshold`, instead
+/// of clipping — the harmonic-rich "folding" distortion sound.
+fn wavefold(x: f32, threshold: f64) -> f32 {
+    let threshold = threshold as f32;
+    let mut x = x;
+    while x.abs() > threshold {
+        x = if x > threshold {
+            2.0 * threshold - x
+        } else {
+            -2.0 * threshold - x
+        };
+    }
+    x
+}
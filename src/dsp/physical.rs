@@ -0,0 +1,23 @@
+//! Physical-model generators. Karplus-Strong is the classic cheap one: a
+//! burst of noise excites a feedback delay line tuned to the string
+//! length, with a touch of lowpass filtering in the loop to model the
+//! string losing energy in its higher harmonics fastest.
+
+use fundsp::hacker::*;
+
+use super::feedback::feedback_loop;
+
+/// A plucked string at `freq`, decaying at `damping` (0 = rings forever,
+/// close to 1 = dies out almost immediately). The excitation burst is a
+/// short noise envelope, not a sustained input — like an actual pluck.
+pub fn plucked_string(freq: f64, damping: f64) -> Box<dyn AudioUnit> {
+    let string_length = 1.0 / freq.max(1.0);
+    let feedback_gain = 1.0 - damping.clamp(0.0, 1.0);
+
+    let excitation = noise() * envelope(move |t| if t < string_length { 1.0 } else { 0.0 });
+    let loop_filter = feedback_loop(
+        delay(string_length as f32) >> lowpass_hz(freq as f32 * 4.0, 1.0),
+        feedback_gain,
+    );
+    Box::new(excitation >> loop_filter)
+}
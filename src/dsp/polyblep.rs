@@ -0,0 +1,100 @@
+//! Band-limited saw and square oscillators using the PolyBLEP technique:
+//! a naive phase-ramp oscillator with a small polynomial correction
+//! applied right at each discontinuity to suppress the aliasing a bare
+//! sawtooth/square would otherwise produce. Stateful per-sample structs,
+//! like [`super::chip::Lfsr`], rather than a `fundsp` combinator, since
+//! the correction needs to see the raw phase increment each sample.
+
+/// The polynomial correction applied within `dt` of a discontinuity at
+/// phase `t` (both normalized to one cycle).
+fn poly_blep(t: f64, dt: f64) -> f64 {
+    if t < dt {
+        let t = t / dt;
+        t + t - t * t - 1.0
+    } else if t > 1.0 - dt {
+        let t = (t - 1.0) / dt;
+        t * t + t + t + 1.0
+    } else {
+        0.0
+    }
+}
+
+pub struct PolyBlepSaw {
+    phase: f64,
+    freq: f64,
+    sample_rate: f64,
+}
+
+impl PolyBlepSaw {
+    pub fn new(freq: f64, sample_rate: f64) -> Self {
+        Self {
+            phase: 0.0,
+            freq,
+            sample_rate,
+        }
+    }
+
+    pub fn set_freq(&mut self, freq: f64) {
+        self.freq = freq;
+    }
+
+    pub fn tick(&mut self) -> f64 {
+        let dt = self.freq / self.sample_rate;
+        let value = 2.0 * self.phase - 1.0 - poly_blep(self.phase, dt);
+        self.phase = (self.phase + dt) % 1.0;
+        value
+    }
+}
+
+pub struct PolyBlepSquare {
+    phase: f64,
+    freq: f64,
+    sample_rate: f64,
+}
+
+impl PolyBlepSquare {
+    pub fn new(freq: f64, sample_rate: f64) -> Self {
+        Self {
+            phase: 0.0,
+            freq,
+            sample_rate,
+        }
+    }
+
+    pub fn set_freq(&mut self, freq: f64) {
+        self.freq = freq;
+    }
+
+    pub fn tick(&mut self) -> f64 {
+        let dt = self.freq / self.sample_rate;
+        let mut value = if self.phase < 0.5 { 1.0 } else { -1.0 };
+        value += poly_blep(self.phase, dt);
+        let half_phase = (self.phase + 0.5) % 1.0;
+        value -= poly_blep(half_phase, dt);
+        self.phase = (self.phase + dt) % 1.0;
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saw_ramps_and_wraps_over_one_cycle() {
+        let mut saw = PolyBlepSaw::new(1.0, 4.0);
+        let samples: Vec<f64> = (0..4).map(|_| saw.tick()).collect();
+        // Away from the wrap-around discontinuity, it's just a bare ramp.
+        assert!((samples[1] - (-0.5)).abs() < 0.6);
+    }
+
+    #[test]
+    fn square_alternates_sign_each_half_cycle() {
+        let mut square = PolyBlepSquare::new(1.0, 4.0);
+        let samples: Vec<f64> = (0..4).map(|_| square.tick()).collect();
+        // Samples 0 and 2 land exactly on the edges, where PolyBLEP
+        // smooths the step to 0; 1 and 3 sit mid-way through each half.
+        assert!(samples[1] > 0.0);
+        assert!(samples[3] < 0.0);
+    }
+}
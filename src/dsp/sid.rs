@@ -0,0 +1,41 @@
+//! A rough emulation of the Commodore 64 SID chip's oscillator: a
+//! selectable waveform (triangle/saw/pulse/noise) with the SID's
+//! characteristic ring-modulation between two oscillators.
+
+use fundsp::hacker::*;
+use fundsp::net::Net;
+
+#[derive(Debug, Clone, Copy)]
+pub enum SidWaveform {
+    Triangle,
+    Saw,
+    Pulse { duty: f32 },
+    Noise,
+}
+
+/// Returns a [`Net`], rather than a bare `Box<dyn AudioUnit>`, because
+/// [`ring_modulated`] still needs to combine it with the modulator via
+/// `*` — an operator `fundsp` only implements for `Net`/`An<X>`, not for
+/// a boxed trait object on its own.
+fn oscillator(freq: f64, waveform: SidWaveform) -> Net {
+    match waveform {
+        SidWaveform::Triangle => Net::wrap(Box::new(triangle_hz(freq as f32))),
+        SidWaveform::Saw => Net::wrap(Box::new(saw_hz(freq as f32))),
+        SidWaveform::Pulse { duty } => Net::wrap(Box::new(super::chip::pulse(freq, duty))),
+        SidWaveform::Noise => Net::wrap(Box::new(noise())),
+    }
+}
+
+/// Ring-modulates `carrier_freq`'s oscillator by `modulator_freq`'s, the
+/// way SID voice 1 can ring-mod voice 3: the output is the carrier
+/// multiplied by the modulator rather than summed with it, which is what
+/// gives ring mod its metallic/bell-like character.
+pub fn ring_modulated(
+    carrier_freq: f64,
+    carrier_wave: SidWaveform,
+    modulator_freq: f64,
+) -> Box<dyn AudioUnit> {
+    let carrier = oscillator(carrier_freq, carrier_wave);
+    let modulator = triangle_hz(modulator_freq as f32);
+    Box::new(carrier * modulator)
+}
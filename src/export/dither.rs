@@ -0,0 +1,62 @@
+//! Shared 16-bit quantization for the encoders that need PCM16 input
+//! (`wav`, `flac`, `mp3`) — centralized so dithering is applied
+//! consistently instead of three copies of the same clamp-and-cast.
+
+/// Quantizes `samples` to 16-bit PCM, clamping to the representable range
+/// first. With `dither` set, adds triangular (TPDF) dither before
+/// rounding to mask quantization distortion at the cost of a small noise
+/// floor — standard practice for a final render, not something you'd want
+/// applied to every intermediate bounce.
+pub fn quantize_i16(samples: &[f32], dither: bool) -> Vec<i16> {
+    let mut rng = DitherRng::new();
+    samples
+        .iter()
+        .map(|&sample| {
+            let mut scaled = sample.clamp(-1.0, 1.0) * i16::MAX as f32;
+            if dither {
+                scaled += rng.triangular();
+            }
+            scaled.round().clamp(i16::MIN as f32, i16::MAX as f32) as i16
+        })
+        .collect()
+}
+
+/// A tiny xorshift PRNG feeding two uniform draws into a triangular
+/// distribution, so dithering doesn't need a `rand` dependency.
+struct DitherRng {
+    state: u32,
+}
+
+impl DitherRng {
+    fn new() -> Self {
+        Self { state: 0x2545_F491 }
+    }
+
+    fn uniform(&mut self) -> f32 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 17;
+        self.state ^= self.state << 5;
+        self.state as f32 / u32::MAX as f32 - 0.5
+    }
+
+    fn triangular(&mut self) -> f32 {
+        self.uniform() + self.uniform()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantizes_full_scale_samples_to_the_i16_extremes() {
+        assert_eq!(quantize_i16(&[1.0, -1.0], false), vec![32767, -32767]);
+    }
+
+    #[test]
+    fn dithering_perturbs_an_otherwise_constant_quiet_signal() {
+        let undithered = quantize_i16(&[0.0001; 8], false);
+        let dithered = quantize_i16(&[0.0001; 8], true);
+        assert_ne!(undithered, dithered);
+    }
+}
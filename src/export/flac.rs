@@ -0,0 +1,26 @@
+#![cfg(feature = "flac")]
+
+use std::path::Path;
+
+use flacenc::config::Encoder as EncoderConfig;
+use flacenc::source::MemSource;
+
+use super::dither::quantize_i16;
+
+/// Encodes interleaved stereo `f32` samples to a FLAC file.
+pub fn write(path: &Path, samples: &[f32], sample_rate: u32, dither: bool) -> anyhow::Result<()> {
+    let ints: Vec<i32> = quantize_i16(samples, dither)
+        .into_iter()
+        .map(i32::from)
+        .collect();
+
+    let source = MemSource::from_samples(&ints, 2, 16, sample_rate as usize);
+    let config = EncoderConfig::default();
+    let flac_stream = flacenc::encode_with_fixed_block_size(&config, source, config.block_size)
+        .map_err(|e| anyhow::anyhow!("flac encode failed: {e:?}"))?;
+
+    let mut sink = flacenc::bitsink::ByteSink::new();
+    flac_stream.write(&mut sink)?;
+    std::fs::write(path, sink.as_slice())?;
+    Ok(())
+}
@@ -0,0 +1,74 @@
+//! Loudness measurement and gain adjustment applied to a render before
+//! it's encoded.
+//!
+//! [`rms_dbfs`] is a simple mean-square level, not a full ITU-R BS.1770
+//! (K-weighted, gated) LUFS meter — close enough to bring a render into a
+//! target ballpark via `--lufs`, not accurate enough to trust for
+//! loudness-war compliance checking.
+
+/// RMS level of `samples`, in dBFS (0 dBFS is a full-scale square wave;
+/// a full-scale sine reads about -3 dBFS).
+/// `f64::NEG_INFINITY` for an empty or all-zero buffer.
+pub fn rms_dbfs(samples: &[f32]) -> f64 {
+    if samples.is_empty() {
+        return f64::NEG_INFINITY;
+    }
+    let mean_square: f64 =
+        samples.iter().map(|&s| (s as f64).powi(2)).sum::<f64>() / samples.len() as f64;
+    10.0 * mean_square.log10()
+}
+
+/// Scales `samples` so its peak sits at `target_peak` (linear, `0..=1`).
+/// A no-op on silence, since there's no peak to scale from.
+pub fn peak_normalize(samples: &mut [f32], target_peak: f32) {
+    let peak = samples.iter().fold(0.0_f32, |acc, &s| acc.max(s.abs()));
+    if peak <= 0.0 {
+        return;
+    }
+    let gain = target_peak / peak;
+    for sample in samples.iter_mut() {
+        *sample *= gain;
+    }
+}
+
+/// Scales `samples` so its RMS level sits at `target_lufs` (see the
+/// module doc for how "LUFS" is approximated here), clamping the result
+/// so a near-silent buffer isn't amplified into clipping.
+pub fn normalize_to_lufs(samples: &mut [f32], target_lufs: f64) {
+    let current = rms_dbfs(samples);
+    if !current.is_finite() {
+        return;
+    }
+    let gain = 10f64.powf((target_lufs - current) / 20.0) as f32;
+    for sample in samples.iter_mut() {
+        *sample = (*sample * gain).clamp(-1.0, 1.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peak_normalize_scales_so_the_loudest_sample_hits_the_target() {
+        let mut samples = [0.25, -0.5, 0.1];
+        peak_normalize(&mut samples, 1.0);
+        assert_eq!(samples, [0.5, -1.0, 0.2]);
+    }
+
+    #[test]
+    fn peak_normalize_leaves_silence_untouched() {
+        let mut samples = [0.0, 0.0];
+        peak_normalize(&mut samples, 1.0);
+        assert_eq!(samples, [0.0, 0.0]);
+    }
+
+    #[test]
+    fn normalize_to_lufs_moves_rms_toward_the_target() {
+        let mut samples = vec![0.1_f32; 100];
+        let before = rms_dbfs(&samples);
+        normalize_to_lufs(&mut samples, -6.0);
+        let after = rms_dbfs(&samples);
+        assert!((after - -6.0).abs() < (before - -6.0).abs());
+    }
+}
@@ -0,0 +1,133 @@
+//! Rendering a patch's master bus to a file on disk.
+//!
+//! [`ExportFormat`] enumerates the encoders this build was compiled with;
+//! each one lives in its own submodule so the `mp3`/`ogg`/`flac` feature
+//! flags can pull in just the encoder crate they need.
+
+mod dither;
+mod flac;
+mod loudness;
+mod mp3;
+mod ogg;
+pub mod piano_roll;
+mod wav;
+
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    Wav,
+    #[cfg(feature = "mp3")]
+    Mp3,
+    #[cfg(feature = "ogg")]
+    Ogg,
+    #[cfg(feature = "flac")]
+    Flac,
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct ExportArgs {
+    /// Where to write the rendered audio.
+    #[arg(short, long)]
+    pub output: PathBuf,
+
+    /// Container/codec to encode to.
+    #[arg(long, value_enum, default_value = "wav")]
+    pub format: ExportFormat,
+
+    /// Sample rate to render at, in Hz.
+    #[arg(long, default_value_t = 44_100)]
+    pub sample_rate: u32,
+
+    /// Render each instrument/mixer bus to its own file instead of one
+    /// mixed-down master file, so tracks can be remixed externally. Each
+    /// stem is written next to `output`, named `<stem>.<bus>.<ext>`.
+    #[arg(long)]
+    pub stems: bool,
+
+    /// Keep rendering past the detected song end (`Song.SetLength` or
+    /// `_G.EndSong`) until the output falls below `tail_threshold_db`,
+    /// instead of cutting off exactly at the nominal length — catches
+    /// reverb/delay tails that ring past the last note.
+    #[arg(long)]
+    pub tail: bool,
+
+    /// Silence threshold for `--tail`, in dBFS.
+    #[arg(long, default_value_t = -60.0)]
+    pub tail_threshold_db: f64,
+
+    /// Peak-normalize the render to 0 dBFS before encoding. Ignored if
+    /// `--lufs` is also given.
+    #[arg(long)]
+    pub normalize: bool,
+
+    /// Normalize the render to this loudness target (see
+    /// [`loudness`] for how "LUFS" is approximated here) instead of
+    /// `--normalize`'s plain peak normalization.
+    #[arg(long)]
+    pub lufs: Option<f64>,
+
+    /// Apply triangular dither when quantizing to 16-bit PCM
+    /// (`wav`/`flac`/`mp3`). No effect on `ogg`, which stays float
+    /// end-to-end.
+    #[arg(long)]
+    pub dither: bool,
+}
+
+/// Encodes a stream of interleaved stereo `f32` samples to `args.output`
+/// in the requested `args.format`, after applying whatever normalization
+/// `args` asked for.
+pub fn export(samples: &[f32], args: &ExportArgs) -> anyhow::Result<()> {
+    let mut samples = samples.to_vec();
+    if let Some(target) = args.lufs {
+        loudness::normalize_to_lufs(&mut samples, target);
+    } else if args.normalize {
+        loudness::peak_normalize(&mut samples, 1.0);
+    }
+
+    match args.format {
+        ExportFormat::Wav => wav::write(&args.output, &samples, args.sample_rate, args.dither),
+        #[cfg(feature = "mp3")]
+        ExportFormat::Mp3 => mp3::write(&args.output, &samples, args.sample_rate, args.dither),
+        #[cfg(feature = "ogg")]
+        ExportFormat::Ogg => ogg::write(&args.output, &samples, args.sample_rate),
+        #[cfg(feature = "flac")]
+        ExportFormat::Flac => flac::write(&args.output, &samples, args.sample_rate, args.dither),
+    }
+}
+
+/// Encodes one file per named stem, next to where `export` would have
+/// written a single master file. `stems` pairs a bus/instrument name with
+/// its already-rendered samples — this function only knows how to fan a
+/// render back out to one-file-per-name, not how to actually produce
+/// per-bus audio, the same way [`export`] only knows how to encode a
+/// buffer it's handed.
+pub fn export_stems(stems: &[(String, Vec<f32>)], args: &ExportArgs) -> anyhow::Result<()> {
+    for (name, samples) in stems {
+        let stem_args = ExportArgs {
+            output: stem_path(&args.output, name),
+            ..args.clone()
+        };
+        export(samples, &stem_args)?;
+    }
+    Ok(())
+}
+
+fn stem_path(output: &Path, name: &str) -> PathBuf {
+    let stem = output.file_stem().and_then(|s| s.to_str()).unwrap_or("export");
+    let extension = output.extension().and_then(|s| s.to_str()).unwrap_or("wav");
+    output.with_file_name(format!("{stem}.{name}.{extension}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stem_path_inserts_the_bus_name_before_the_extension() {
+        let output = PathBuf::from("/tmp/song.wav");
+        assert_eq!(stem_path(&output, "drums"), PathBuf::from("/tmp/song.drums.wav"));
+    }
+}
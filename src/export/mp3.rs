@@ -0,0 +1,25 @@
+#![cfg(feature = "mp3")]
+
+use std::path::Path;
+
+use mp3lame_encoder::{Builder, FlushNoGap, InterleavedPcm};
+
+use super::dither::quantize_i16;
+
+/// Encodes interleaved stereo `f32` samples to MP3 via libmp3lame.
+pub fn write(path: &Path, samples: &[f32], sample_rate: u32, dither: bool) -> anyhow::Result<()> {
+    let mut builder = Builder::new().ok_or_else(|| anyhow::anyhow!("failed to init lame"))?;
+    builder.set_num_channels(2)?;
+    builder.set_sample_rate(sample_rate)?;
+    builder.set_quality(mp3lame_encoder::Quality::Best)?;
+    let mut encoder = builder.build()?;
+
+    let pcm: Vec<i16> = quantize_i16(samples, dither);
+
+    let mut out = Vec::new();
+    encoder.encode_to_vec(InterleavedPcm(&pcm), &mut out)?;
+    encoder.flush_to_vec::<FlushNoGap>(&mut out)?;
+
+    std::fs::write(path, out)?;
+    Ok(())
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "ogg")]
+
+use std::fs::File;
+use std::num::NonZeroU32;
+use std::path::Path;
+
+use vorbis_rs::VorbisEncoderBuilder;
+
+/// Encodes interleaved stereo `f32` samples to an Ogg/Vorbis file.
+pub fn write(path: &Path, samples: &[f32], sample_rate: u32) -> anyhow::Result<()> {
+    let file = File::create(path)?;
+    let mut encoder = VorbisEncoderBuilder::new(
+        NonZeroU32::new(sample_rate).ok_or_else(|| anyhow::anyhow!("zero sample rate"))?,
+        NonZeroU32::new(2).unwrap(),
+        file,
+    )?
+    .build()?;
+
+    let (left, right): (Vec<f32>, Vec<f32>) = samples
+        .chunks_exact(2)
+        .map(|pair| (pair[0], pair[1]))
+        .unzip();
+    encoder.encode_audio_block([&left, &right])?;
+    encoder.finish()?;
+    Ok(())
+}
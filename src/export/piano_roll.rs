@@ -0,0 +1,61 @@
+//! Rendering a [`crate::sequence::Sequence`] as a piano-roll SVG: one row
+//! per MIDI note, one column per step, for looking at a pattern without
+//! opening a DAW.
+
+use std::path::Path;
+
+use crate::sequence::Sequence;
+
+const STEP_WIDTH: u32 = 20;
+const ROW_HEIGHT: u32 = 6;
+const LOWEST_NOTE: u8 = 24; // C1, low enough for most bass/drum patterns
+const HIGHEST_NOTE: u8 = 96; // C7
+
+pub fn write(path: &Path, sequence: &Sequence) -> anyhow::Result<()> {
+    let svg = render(sequence);
+    std::fs::write(path, svg)?;
+    Ok(())
+}
+
+fn render(sequence: &Sequence) -> String {
+    let rows = (HIGHEST_NOTE - LOWEST_NOTE + 1) as u32;
+    let width = sequence.len() as u32 * STEP_WIDTH;
+    let height = rows * ROW_HEIGHT;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\">\n\
+         <rect width=\"{width}\" height=\"{height}\" fill=\"#111\"/>\n"
+    );
+
+    for (step_index, step) in sequence.steps().iter().enumerate() {
+        let Some(note) = step.note() else {
+            continue;
+        };
+        if note.0 < LOWEST_NOTE || note.0 > HIGHEST_NOTE {
+            continue;
+        }
+        let x = step_index as u32 * STEP_WIDTH;
+        let y = (HIGHEST_NOTE - note.0) as u32 * ROW_HEIGHT;
+        svg += &format!(
+            "<rect x=\"{x}\" y=\"{y}\" width=\"{STEP_WIDTH}\" height=\"{ROW_HEIGHT}\" fill=\"#4caf50\"/>\n"
+        );
+    }
+
+    svg += "</svg>\n";
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::note::MidiNote;
+    use crate::sequence::Step;
+
+    #[test]
+    fn renders_one_rect_per_note() {
+        let sequence = Sequence::new(vec![Step::Rest, Step::Note(MidiNote(60))]);
+        let svg = render(&sequence);
+        assert_eq!(svg.matches("<rect").count(), 2); // background + one note
+    }
+}
@@ -0,0 +1,21 @@
+use std::path::Path;
+
+use hound::{SampleFormat, WavSpec, WavWriter};
+
+use super::dither::quantize_i16;
+
+/// Writes interleaved stereo `f32` samples as a 16-bit PCM WAV file.
+pub fn write(path: &Path, samples: &[f32], sample_rate: u32, dither: bool) -> anyhow::Result<()> {
+    let spec = WavSpec {
+        channels: 2,
+        sample_rate,
+        bits_per_sample: 16,
+        sample_format: SampleFormat::Int,
+    };
+    let mut writer = WavWriter::create(path, spec)?;
+    for sample in quantize_i16(samples, dither) {
+        writer.write_sample(sample)?;
+    }
+    writer.finalize()?;
+    Ok(())
+}
@@ -0,0 +1,45 @@
+//! systemd/watchdog-friendly health reporting for unattended deployments
+//! (see [`crate::daemon`]). No-ops cleanly when not run under systemd,
+//! since `sd_notify` detects the absence of `NOTIFY_SOCKET` itself.
+
+use std::time::{Duration, Instant};
+
+/// Sends `READY=1` once and then `WATCHDOG=1` at half the interval
+/// systemd's unit file asked for (`WatchdogSec`), if any.
+pub struct Watchdog {
+    interval: Option<Duration>,
+    last_ping: Instant,
+}
+
+impl Watchdog {
+    pub fn start() -> anyhow::Result<Self> {
+        sd_notify::notify(false, &[sd_notify::NotifyState::Ready])?;
+        let interval = sd_notify::watchdog_enabled().map(|micros| Duration::from_micros(micros) / 2);
+        Ok(Self {
+            interval,
+            last_ping: Instant::now(),
+        })
+    }
+
+    /// Call periodically from the main loop; pings the watchdog at most
+    /// once per interval.
+    pub fn tick(&mut self) -> anyhow::Result<()> {
+        let Some(interval) = self.interval else {
+            return Ok(());
+        };
+        if self.last_ping.elapsed() >= interval {
+            sd_notify::notify(false, &[sd_notify::NotifyState::Watchdog])?;
+            self.last_ping = Instant::now();
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        // Best-effort: systemd treats a missing STOPPING as "crashed" only
+        // once the watchdog interval lapses, so a failed notify here isn't
+        // worth propagating.
+        let _ = sd_notify::notify(false, &[sd_notify::NotifyState::Stopping]);
+    }
+}
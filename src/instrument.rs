@@ -0,0 +1,283 @@
+//! Binds a `fundsp` `Net` template to a set of named voices, each with
+//! its own frequency control, so the same patch (e.g. "pluck") can be
+//! played at many pitches at once.
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use fundsp::hacker::*;
+use fundsp::net::Net;
+
+/// A voice is the template net wired to a per-voice frequency `var`, so
+/// setting the frequency doesn't require rebuilding the graph. `fundsp`
+/// itself is `f32`-only; the `f64` here is kept at the voice boundary so
+/// callers (note frequencies, gains) don't have to think about the
+/// narrower precision `fundsp` runs its graphs at internally.
+pub struct Voice {
+    freq: Shared,
+    net: Net,
+    /// A net mid-crossfade-in, with samples remaining and the total
+    /// crossfade length, for [`Voice::net_replace`].
+    pending: Option<(Net, u32, u32)>,
+    /// Per-event gain, set when the note was triggered (e.g. from a
+    /// sequence step's velocity) and left alone afterward. Also what
+    /// [`Instrument::voice`] reads to decide which voice is quietest
+    /// when it has to steal one to stay under a voice cap.
+    gain: f64,
+}
+
+impl Voice {
+    pub fn set_freq(&self, freq: f64) {
+        self.freq.set_value(freq as f32);
+    }
+
+    pub fn set_gain(&mut self, gain: f64) {
+        self.gain = gain.clamp(0.0, 1.0);
+    }
+
+    /// Swaps in `new_net` without clicking: the outgoing and incoming
+    /// nets are both ticked and linearly crossfaded over
+    /// `crossfade_samples`, instead of just dropping the old backend.
+    pub fn net_replace(&mut self, new_net: Net, crossfade_samples: u32) {
+        self.pending = Some((new_net, crossfade_samples, std::cmp::max(crossfade_samples, 1)));
+    }
+
+    pub fn tick(&mut self) -> f64 {
+        let current = self.net.get_mono() as f64;
+        let Some((mut new_net, mut remaining, total)) = self.pending.take() else {
+            return current * self.gain;
+        };
+
+        let incoming = new_net.get_mono() as f64;
+        let t = 1.0 - (remaining as f64 / total as f64);
+        let mixed = current * (1.0 - t) + incoming * t;
+
+        remaining = remaining.saturating_sub(1);
+        if remaining == 0 {
+            self.net = new_net;
+        } else {
+            self.pending = Some((new_net, remaining, total));
+        }
+        mixed * self.gain
+    }
+}
+
+/// A cap on how many voices can be live across every [`Instrument`]
+/// sharing one of these, not just a single instrument — so a handful of
+/// instruments, each comfortably under its own per-instrument cap, can
+/// still be refused a voice once the ensemble-wide total is reached.
+/// Instruments that don't need a global cap just don't share one.
+#[derive(Clone)]
+pub struct VoiceBudget(Rc<Cell<usize>>);
+
+impl VoiceBudget {
+    pub fn new(max_voices: usize) -> Self {
+        Self(Rc::new(Cell::new(max_voices)))
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.0.get()
+    }
+
+    fn try_acquire(&self) -> bool {
+        let remaining = self.0.get();
+        if remaining == 0 {
+            return false;
+        }
+        self.0.set(remaining - 1);
+        true
+    }
+
+    fn release(&self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+/// A named collection of voices built from the same template.
+pub struct Instrument {
+    template: Box<dyn Fn(Shared) -> Net>,
+    voices: HashMap<String, Voice>,
+    /// Insertion order, oldest first — nothing here counts as "the
+    /// voice list" on its own, it only exists to break ties when
+    /// [`Instrument::voice`] has several equally-quiet voices to choose
+    /// a steal victim from.
+    order: Vec<String>,
+    max_voices: Option<usize>,
+    budget: Option<VoiceBudget>,
+}
+
+impl Instrument {
+    pub fn new(template: impl Fn(Shared) -> Net + 'static) -> Self {
+        Self {
+            template: Box::new(template),
+            voices: HashMap::new(),
+            order: Vec::new(),
+            max_voices: None,
+            budget: None,
+        }
+    }
+
+    /// Caps how many voices this instrument alone can have live at
+    /// once. Once at the cap, [`Instrument::voice`] steals the quietest
+    /// existing voice (oldest, on a tie) to make room for a new one,
+    /// rather than growing without bound the way a runaway Lua loop
+    /// retriggering the same instrument every tick otherwise would.
+    pub fn set_max_voices(&mut self, max_voices: usize) {
+        self.max_voices = Some(max_voices);
+    }
+
+    /// Additionally caps this instrument against a [`VoiceBudget`]
+    /// shared with other instruments, so the ensemble as a whole can't
+    /// exceed it even if no single instrument is over its own cap.
+    pub fn set_budget(&mut self, budget: VoiceBudget) {
+        self.budget = Some(budget);
+    }
+
+    /// Creates (or retriggers) the named voice at the given starting
+    /// frequency and gain (e.g. note velocity). Returns `None` instead
+    /// of creating the voice if it would exceed a configured cap and
+    /// there was nothing left to steal to make room — the caller should
+    /// treat that the same as a step that landed on a rest.
+    pub fn voice(&mut self, name: &str, freq: f64, gain: f64) -> Option<&mut Voice> {
+        let is_new = !self.voices.contains_key(name);
+        if is_new {
+            if !self.make_room() {
+                return None;
+            }
+        } else {
+            self.order.retain(|n| n != name);
+        }
+
+        let shared = shared(freq as f32);
+        let net = (self.template)(shared.clone());
+        self.voices.insert(
+            name.to_string(),
+            Voice {
+                freq: shared,
+                net,
+                pending: None,
+                gain: gain.clamp(0.0, 1.0),
+            },
+        );
+        self.order.push(name.to_string());
+        self.voices.get_mut(name)
+    }
+
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Voice> {
+        self.voices.get_mut(name)
+    }
+
+    /// Rebuilds the named voice's net from the current template, at its
+    /// existing frequency, and crossfades into it click-free — for live
+    /// patching where the DSP graph itself changes mid-performance.
+    pub fn net_replace(&mut self, name: &str, crossfade_samples: u32) {
+        let Some(voice) = self.voices.get_mut(name) else {
+            return;
+        };
+        let new_net = (self.template)(voice.freq.clone());
+        voice.net_replace(new_net, crossfade_samples);
+    }
+
+    /// Reserves a slot for one more voice, stealing the quietest
+    /// existing one first if the per-instrument cap or the shared
+    /// budget needs the room. Returns `false` if a slot still couldn't
+    /// be found — the instrument had nothing of its own to steal and
+    /// the shared budget (if any) had no room left.
+    fn make_room(&mut self) -> bool {
+        if let Some(max) = self.max_voices {
+            if self.voices.len() >= max {
+                self.steal_quietest();
+            }
+        }
+        let Some(budget) = self.budget.clone() else {
+            return true;
+        };
+        if budget.try_acquire() {
+            return true;
+        }
+        self.steal_quietest() && budget.try_acquire()
+    }
+
+    /// Evicts the voice with the lowest gain, oldest first on a tie,
+    /// releasing its shared budget slot (if any). Returns `false` if
+    /// there were no voices to steal from.
+    fn steal_quietest(&mut self) -> bool {
+        let Some(victim) = self
+            .order
+            .iter()
+            .min_by(|a, b| {
+                let gain_a = self.voices.get(*a).map_or(0.0, |v| v.gain);
+                let gain_b = self.voices.get(*b).map_or(0.0, |v| v.gain);
+                gain_a.partial_cmp(&gain_b).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .cloned()
+        else {
+            return false;
+        };
+        self.voices.remove(&victim);
+        self.order.retain(|n| n != &victim);
+        if let Some(budget) = &self.budget {
+            budget.release();
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn silent_template(freq: Shared) -> Net {
+        Net::wrap(Box::new(sine_hz(freq.value())))
+    }
+
+    #[test]
+    fn steals_the_quietest_voice_at_the_cap() {
+        let mut instrument = Instrument::new(silent_template);
+        instrument.set_max_voices(2);
+        instrument.voice("a", 440.0, 1.0);
+        instrument.voice("b", 440.0, 0.1);
+
+        assert!(instrument.voice("c", 440.0, 0.5).is_some());
+        assert!(instrument.get_mut("b").is_none());
+        assert!(instrument.get_mut("a").is_some());
+        assert!(instrument.get_mut("c").is_some());
+    }
+
+    #[test]
+    fn breaks_gain_ties_by_stealing_the_oldest() {
+        let mut instrument = Instrument::new(silent_template);
+        instrument.set_max_voices(2);
+        instrument.voice("oldest", 440.0, 0.5);
+        instrument.voice("newest", 440.0, 0.5);
+
+        instrument.voice("incoming", 440.0, 0.5);
+        assert!(instrument.get_mut("oldest").is_none());
+        assert!(instrument.get_mut("newest").is_some());
+    }
+
+    #[test]
+    fn retriggering_an_existing_voice_does_not_steal() {
+        let mut instrument = Instrument::new(silent_template);
+        instrument.set_max_voices(1);
+        instrument.voice("a", 440.0, 0.2);
+
+        assert!(instrument.voice("a", 880.0, 0.9).is_some());
+        assert!(instrument.get_mut("a").is_some());
+    }
+
+    #[test]
+    fn drops_new_voices_once_the_shared_budget_is_exhausted() {
+        let budget = VoiceBudget::new(1);
+
+        let mut drums = Instrument::new(silent_template);
+        drums.set_budget(budget.clone());
+        let mut bass = Instrument::new(silent_template);
+        bass.set_budget(budget.clone());
+
+        assert!(drums.voice("kick", 60.0, 1.0).is_some());
+        assert_eq!(budget.remaining(), 0);
+        assert!(bass.voice("low-e", 41.0, 1.0).is_none());
+    }
+}
@@ -0,0 +1,97 @@
+//! Polls the terminal for keypresses and fires Lua callbacks registered
+//! against them (`Keys.OnPress`), so a patch can be played from the
+//! laptop keyboard during `octmm play` instead of only reacting to
+//! scheduled time.
+//!
+//! Raw mode is only turned on the first time this module actually ticks
+//! (not merely when it's registered) — `check`/`repl` install the same
+//! `Lua` globals as `play` but never tick this module, and shouldn't
+//! have the terminal put into raw mode just for loading a script.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
+
+use crossterm::event::{self, Event, KeyCode};
+use crossterm::terminal;
+use mlua::{Function, RegistryKey};
+
+use crate::context::Context;
+use crate::runner::Module;
+
+pub struct KeysModule {
+    callbacks: HashMap<char, RegistryKey>,
+    raw_mode: bool,
+}
+
+impl Default for KeysModule {
+    fn default() -> Self {
+        Self {
+            callbacks: HashMap::new(),
+            raw_mode: false,
+        }
+    }
+}
+
+impl KeysModule {
+    /// Registers `func` to fire every time `key` (a single character, as
+    /// typed) is pressed. Re-registering the same key replaces whatever
+    /// was there before, the same way a second `Timer.every` wouldn't
+    /// merge with an earlier one but a direct overwrite reads as "this
+    /// key now does this instead".
+    pub fn on_press(&mut self, lua: &mlua::Lua, key: char, func: Function) -> anyhow::Result<()> {
+        let registry_key = lua.create_registry_value(func)?;
+        self.callbacks.insert(key, registry_key);
+        Ok(())
+    }
+
+    fn poll_once(&mut self, ctx: &Context) -> anyhow::Result<()> {
+        while event::poll(Duration::ZERO)? {
+            let Event::Key(key_event) = event::read()? else {
+                continue;
+            };
+            let KeyCode::Char(pressed) = key_event.code else {
+                continue;
+            };
+            let Some(registry_key) = self.callbacks.get(&pressed) else {
+                continue;
+            };
+            let func: Function = ctx.lua.registry_value(registry_key)?;
+            if let Err(err) = crate::lua::call_with_traceback(ctx.lua, &func) {
+                log::error!("Keys.OnPress({pressed:?}) callback failed: {err}");
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Module for KeysModule {
+    fn update(&mut self, ctx: &Context) -> anyhow::Result<()> {
+        if !self.raw_mode {
+            terminal::enable_raw_mode()?;
+            self.raw_mode = true;
+        }
+        self.poll_once(ctx)
+    }
+}
+
+impl Drop for KeysModule {
+    fn drop(&mut self) {
+        if self.raw_mode {
+            let _ = terminal::disable_raw_mode();
+        }
+    }
+}
+
+/// Shared handle to a [`KeysModule`], so `Keys.OnPress(...)` (registered
+/// against the Lua state before the runner exists) and the runner's own
+/// tick loop (which owns the module afterwards) can reach the same
+/// instance.
+pub type SharedKeys = Rc<RefCell<KeysModule>>;
+
+impl Module for SharedKeys {
+    fn update(&mut self, ctx: &Context) -> anyhow::Result<()> {
+        self.borrow_mut().update(ctx)
+    }
+}
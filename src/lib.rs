@@ -0,0 +1,44 @@
+pub mod audio;
+pub mod automation;
+pub mod check;
+pub mod cli;
+pub mod command;
+pub mod context;
+pub mod create;
+pub mod crossfader;
+pub mod daemon;
+pub mod dsp;
+pub mod event;
+pub mod export;
+pub mod health;
+pub mod instrument;
+pub mod keys;
+pub mod logging;
+pub mod lua;
+pub mod mapping;
+pub mod midi;
+pub mod mixer;
+pub mod note;
+pub mod osc;
+pub mod output;
+pub mod play;
+pub mod project;
+pub mod record;
+pub mod reference;
+pub mod remote;
+pub mod repl;
+pub mod routing;
+pub mod runner;
+pub mod sample;
+pub mod scheduler;
+pub mod sequence;
+pub mod sequencer;
+pub mod setlist;
+pub mod song;
+pub mod stats;
+pub mod time;
+pub mod timer;
+pub mod tracker;
+pub mod transport;
+pub mod stream;
+pub mod tui;
@@ -0,0 +1,78 @@
+//! A small [`log::Log`] implementation: prints `[elapsed] LEVEL message`
+//! lines, errors and warnings to stderr and every other level to stdout,
+//! and optionally tees the same lines to a file when `--log-file`
+//! is set, so script and engine diagnostics land in one place instead of
+//! disappearing into bare `print`'s unleveled stdout output.
+//!
+//! Timestamps are seconds elapsed since the process started rather than a
+//! wall-clock date — during a live set "12.4s in" is what you want to
+//! correlate against a glitch, not today's date.
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+struct Logger {
+    start: Instant,
+    file: Option<Mutex<File>>,
+}
+
+impl Log for Logger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let line = format!(
+            "[{:>8.3}s] {:<5} {}",
+            self.start.elapsed().as_secs_f64(),
+            record.level(),
+            record.args()
+        );
+
+        match record.level() {
+            Level::Error | Level::Warn => eprintln!("{line}"),
+            _ => println!("{line}"),
+        }
+
+        if let Some(file) = &self.file {
+            if let Ok(mut file) = file.lock() {
+                let _ = writeln!(file, "{line}");
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(file) = &self.file {
+            if let Ok(mut file) = file.lock() {
+                let _ = file.flush();
+            }
+        }
+    }
+}
+
+/// Installs the global logger at `level`, optionally also appending every
+/// line to `log_file`. Must be called at most once per process, same as
+/// any `log::set_logger` caller.
+pub fn init(level: LevelFilter, log_file: Option<&Path>) -> anyhow::Result<()> {
+    let file = match log_file {
+        Some(path) => Some(Mutex::new(
+            OpenOptions::new().create(true).append(true).open(path)?,
+        )),
+        None => None,
+    };
+
+    log::set_max_level(level);
+    log::set_boxed_logger(Box::new(Logger {
+        start: Instant::now(),
+        file,
+    }))
+    .map_err(|err| anyhow::anyhow!("logger already initialized: {err}"))
+}
@@ -0,0 +1,47 @@
+//! Caches compiled Luau bytecode per project, keyed by a hash of the
+//! source, so re-running an unchanged project skips recompilation.
+
+use std::path::{Path, PathBuf};
+
+use mlua::{Compiler, Lua};
+
+fn cache_dir(project_root: &Path) -> PathBuf {
+    project_root.join(".octmm-cache")
+}
+
+fn cache_path(project_root: &Path, source: &str) -> PathBuf {
+    cache_dir(project_root).join(format!("{:016x}.luac", fnv1a(source)))
+}
+
+/// Loads `source` as a Lua chunk, compiling fresh and writing the result
+/// to the project's cache directory if there isn't already a hit.
+pub fn load_cached<'lua>(
+    lua: &'lua Lua,
+    project_root: &Path,
+    source: &str,
+    chunk_name: &str,
+) -> anyhow::Result<mlua::Function<'lua>> {
+    let path = cache_path(project_root, source);
+
+    let bytecode = if let Ok(cached) = std::fs::read(&path) {
+        cached
+    } else {
+        let compiled = Compiler::new().compile(source);
+        std::fs::create_dir_all(cache_dir(project_root))?;
+        std::fs::write(&path, &compiled)?;
+        compiled
+    };
+
+    Ok(lua.load(&bytecode).set_name(chunk_name).into_function()?)
+}
+
+/// A tiny non-cryptographic hash; this only needs to be stable and cheap,
+/// not collision-resistant against an adversary.
+fn fnv1a(data: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in data.as_bytes() {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
@@ -0,0 +1,37 @@
+//! The `Engine` table: read-only diagnostics about the running session
+//! (currently just achieved tick rate and jitter), for patches that want
+//! to log or display their own performance info instead of relying on a
+//! separate status display.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use mlua::{Lua, Table};
+
+use crate::runner::JitterStats;
+
+/// Updated by whoever owns the [`crate::runner::Runner`] after every
+/// tick, and read from the Lua closures registered below.
+pub type SharedJitter = Rc<Cell<JitterStats>>;
+
+pub fn register(lua: &Lua, jitter: SharedJitter) -> anyhow::Result<()> {
+    let table: Table = lua.create_table()?;
+
+    {
+        let jitter = jitter.clone();
+        table.set(
+            "tick_rate",
+            lua.create_function(move |_, ()| {
+                let mean_dt = jitter.get().mean_dt;
+                Ok(if mean_dt > 0.0 { 1.0 / mean_dt } else { 0.0 })
+            })?,
+        )?;
+    }
+    table.set(
+        "jitter",
+        lua.create_function(move |_, ()| Ok(jitter.get().jitter))?,
+    )?;
+
+    lua.globals().set("Engine", table)?;
+    Ok(())
+}
@@ -0,0 +1,44 @@
+//! Running Lua with a Lua-side stack trace attached to its error, instead
+//! of the bare single-line message `mlua::Error` gives by default. Every
+//! place that loads project Lua (modules, entry script) should go
+//! through [`exec_file`], and every place that invokes a Lua callback
+//! stashed away from some earlier call (the runner's timer callbacks)
+//! should go through [`call_with_traceback`], rather than calling
+//! `exec()`/`call()` directly — so a failure always names the file or
+//! callback that caused it and how it got there.
+
+use std::path::Path;
+
+use mlua::{Function, Lua, Table, Value};
+
+/// Loads and runs `source` as a chunk named after `path`, via `xpcall`
+/// with `debug.traceback` as the handler. On error, the returned message
+/// is `<path>: <lua stack trace>` rather than whatever one-line message
+/// the failing call happened to raise.
+pub fn exec_file(lua: &Lua, path: &Path, source: &str) -> anyhow::Result<()> {
+    let chunk = lua
+        .load(source)
+        .set_name(path.display().to_string())
+        .into_function()?;
+    call_with_traceback(lua, &chunk).map_err(|err| anyhow::anyhow!("{}: {err}", path.display()))
+}
+
+/// Calls `func` with no arguments via `xpcall(func, debug.traceback)`,
+/// returning `Err` with the Lua stack trace attached rather than the
+/// opaque single-line message a bare `Function::call` error carries.
+pub fn call_with_traceback(lua: &Lua, func: &Function) -> anyhow::Result<()> {
+    let xpcall: Function = lua.globals().get("xpcall")?;
+    let debug: Table = lua.globals().get("debug")?;
+    let traceback: Function = debug.get("traceback")?;
+
+    let (ok, message): (bool, Value) = xpcall.call((func.clone(), traceback))?;
+    if ok {
+        return Ok(());
+    }
+
+    let message = match message {
+        Value::String(s) => s.to_str()?.to_string(),
+        other => format!("{other:?}"),
+    };
+    anyhow::bail!("{message}");
+}
@@ -0,0 +1,23 @@
+//! The `help()` Lua global: prints every registered command, grouped by
+//! namespace, to stdout.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use mlua::Lua;
+
+use crate::command::CommandRegistry;
+
+pub fn register(lua: &Lua, registry: Rc<RefCell<CommandRegistry>>) -> anyhow::Result<()> {
+    lua.globals().set(
+        "help",
+        lua.create_function(move |_, ()| {
+            let registry = registry
+                .try_borrow()
+                .map_err(|e| mlua::Error::external(format!("command registry busy: {e}")))?;
+            println!("{}", registry.help_text());
+            Ok(())
+        })?,
+    )?;
+    Ok(())
+}
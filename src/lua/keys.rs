@@ -0,0 +1,27 @@
+//! The `Keys` table: `Keys.OnPress(key, fn)` fires `fn` every time `key`
+//! (a single-character string, as typed) is pressed while `octmm play` is
+//! running, so a patch can be triggered live from the laptop keyboard.
+
+use mlua::{Function, Lua, Table};
+
+use crate::keys::SharedKeys;
+
+pub fn register(lua: &Lua, keys: SharedKeys) -> anyhow::Result<()> {
+    let table: Table = lua.create_table()?;
+
+    table.set(
+        "OnPress",
+        lua.create_function(move |lua, (key, func): (String, Function)| {
+            let key = key.chars().next().ok_or_else(|| {
+                mlua::Error::RuntimeError("Keys.OnPress key must not be empty".to_string())
+            })?;
+            keys.try_borrow_mut()
+                .map_err(|e| mlua::Error::external(format!("keys are busy: {e}")))?
+                .on_press(lua, key, func)
+                .map_err(mlua::Error::external)
+        })?,
+    )?;
+
+    lua.globals().set("Keys", table)?;
+    Ok(())
+}
@@ -0,0 +1,35 @@
+//! The `Log` table: `Log.info/warn/error(message)` route through the
+//! standard `log` crate, so messages sent from script land in the same
+//! leveled, timestamped stream as the engine's own diagnostics instead of
+//! disappearing into `print`'s bare stdout output.
+
+use mlua::{Lua, Table};
+
+pub fn register(lua: &Lua) -> anyhow::Result<()> {
+    let table: Table = lua.create_table()?;
+
+    table.set(
+        "info",
+        lua.create_function(|_, message: String| {
+            log::info!("{message}");
+            Ok(())
+        })?,
+    )?;
+    table.set(
+        "warn",
+        lua.create_function(|_, message: String| {
+            log::warn!("{message}");
+            Ok(())
+        })?,
+    )?;
+    table.set(
+        "error",
+        lua.create_function(|_, message: String| {
+            log::error!("{message}");
+            Ok(())
+        })?,
+    )?;
+
+    lua.globals().set("Log", table)?;
+    Ok(())
+}
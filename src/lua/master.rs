@@ -0,0 +1,33 @@
+//! The `Master` table: `Master.set_volume(v)` / `Master.get_volume()`
+//! control the overall output gain applied after every node is mixed
+//! down. Per-event gain (e.g. a single note played quieter) is a
+//! separate multiplier passed alongside that note, not part of this.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use mlua::{Lua, Table};
+
+pub type MasterVolume = Rc<Cell<f64>>;
+
+pub fn register(lua: &Lua, volume: MasterVolume) -> anyhow::Result<()> {
+    let table: Table = lua.create_table()?;
+
+    {
+        let volume = volume.clone();
+        table.set(
+            "set_volume",
+            lua.create_function(move |_, v: f64| {
+                volume.set(v.clamp(0.0, 1.0));
+                Ok(())
+            })?,
+        )?;
+    }
+    table.set(
+        "get_volume",
+        lua.create_function(move |_, ()| Ok(volume.get()))?,
+    )?;
+
+    lua.globals().set("Master", table)?;
+    Ok(())
+}
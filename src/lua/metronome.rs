@@ -0,0 +1,83 @@
+//! The `Metronome` table: `Metronome.start(beats_per_click)` schedules a
+//! click every `beats_per_click` beats via the shared [`SharedTimer`],
+//! accenting the downbeat of each bar with a deeper click than the rest;
+//! `Metronome.stop()` cancels it. Starting a new one implicitly stops
+//! whatever was already running, so a script can change the subdivision
+//! without leaving an orphaned click behind.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use mlua::{Lua, Table};
+
+use crate::dsp::{DspModule, NodeType};
+use crate::timer::SharedTimer;
+
+use super::NodeRegistry;
+
+pub fn register(lua: &Lua, nodes: NodeRegistry, timer: SharedTimer) -> anyhow::Result<()> {
+    let table: Table = lua.create_table()?;
+    let running: Rc<Cell<Option<u64>>> = Rc::new(Cell::new(None));
+
+    {
+        let nodes = nodes.clone();
+        let timer = timer.clone();
+        let running = running.clone();
+        table.set(
+            "start",
+            lua.create_function(move |lua, beats_per_click: f64| {
+                if let Some(id) = running.take() {
+                    timer
+                        .try_borrow_mut()
+                        .map_err(|e| mlua::Error::external(format!("timer is busy: {e}")))?
+                        .cancel(id);
+                }
+
+                let nodes = nodes.clone();
+                let timer_for_click = timer.clone();
+                let click = lua.create_function(move |_, ()| {
+                    let accent = timer_for_click
+                        .try_borrow()
+                        .map_err(|e| mlua::Error::external(format!("timer is busy: {e}")))?
+                        .position()
+                        .beat
+                        == 1;
+                    let node = DspModule::build(&NodeType::MetronomeClick { accent })
+                        .map_err(mlua::Error::external)?;
+                    nodes
+                        .try_borrow_mut()
+                        .map_err(|e| mlua::Error::external(format!("node registry busy: {e}")))?
+                        .push(node);
+                    Ok(())
+                })?;
+
+                let id = timer
+                    .try_borrow_mut()
+                    .map_err(|e| mlua::Error::external(format!("timer is busy: {e}")))?
+                    .add_callback(lua, beats_per_click, 0, click)
+                    .map_err(mlua::Error::external)?;
+                running.set(Some(id));
+                Ok(())
+            })?,
+        )?;
+    }
+    {
+        let timer = timer.clone();
+        let running = running.clone();
+        table.set(
+            "stop",
+            lua.create_function(move |_, ()| {
+                if let Some(id) = running.take() {
+                    timer
+                        .try_borrow_mut()
+                        .map_err(|e| mlua::Error::external(format!("timer is busy: {e}")))?
+                        .cancel(id);
+                }
+                Ok(())
+            })?,
+        )?;
+    }
+
+    lua.globals().set("Metronome", table)?;
+    Ok(())
+}
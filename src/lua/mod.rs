@@ -0,0 +1,112 @@
+//! Bindings exposed to patch scripts. Each family of nodes gets its own
+//! `register_*` function so `octmm::lua::install` can assemble exactly the
+//! globals a given build supports.
+
+mod cache;
+mod engine;
+mod exec;
+mod help;
+mod keys;
+mod log;
+mod master;
+mod metronome;
+mod net_handle;
+mod node_handle;
+mod noise;
+mod rate_limit;
+mod record;
+mod require;
+mod samples;
+mod song;
+mod stats;
+mod tempo;
+mod timer;
+mod transport;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use mlua::Lua;
+
+use crate::command::{CommandInfo, CommandRegistry};
+use crate::dsp::DspModule;
+use crate::keys::SharedKeys;
+use crate::project::Project;
+use crate::song::SongLength;
+use crate::timer::SharedTimer;
+
+pub use cache::load_cached;
+pub use engine::{register as install_engine, SharedJitter};
+pub use exec::{call_with_traceback, exec_file};
+pub use net_handle::NetHandle;
+pub use node_handle::NodeHandle;
+pub use rate_limit::RateLimiter;
+pub use transport::SharedTransport;
+
+/// The set of live DSP nodes a patch has created, shared between the Lua
+/// globals (which create nodes) and the audio thread (which ticks them).
+pub type NodeRegistry = Rc<RefCell<Vec<DspModule>>>;
+
+pub fn install(
+    lua: &Lua,
+    nodes: NodeRegistry,
+    transport: SharedTransport,
+    timer: SharedTimer,
+    keys: SharedKeys,
+    song: SongLength,
+) -> anyhow::Result<()> {
+    let commands = Rc::new(RefCell::new(CommandRegistry::default()));
+
+    noise::register(lua, nodes.clone())?;
+    master::register(lua, Default::default())?;
+    transport::register(lua, transport)?;
+    tempo::register(lua, timer.clone())?;
+    metronome::register(lua, nodes, timer.clone())?;
+    timer::register(lua, timer)?;
+    keys::register(lua, keys)?;
+    log::register(lua)?;
+    record::register(lua, Default::default())?;
+    song::register(lua, song)?;
+    stats::register(lua, Default::default())?;
+    register_builtin_commands(&commands);
+    help::register(lua, commands)?;
+    Ok(())
+}
+
+/// Installs project-scoped globals (`Samples`, `require`), separate from
+/// [`install`] since they need a loaded [`Project`] rather than just a
+/// fresh Lua engine.
+pub fn install_project(lua: &Lua, project: &Project) -> anyhow::Result<()> {
+    samples::register(lua, project)?;
+    require::register(lua, project.root.clone())
+}
+
+fn register_builtin_commands(commands: &Rc<RefCell<CommandRegistry>>) {
+    let mut commands = commands.borrow_mut();
+    for (name, summary) in [
+        ("white", "white noise generator"),
+        ("pink", "pink noise generator"),
+        ("brown", "brown noise generator"),
+    ] {
+        commands.register(CommandInfo {
+            namespace: "noise",
+            name,
+            summary,
+            args: &[],
+        });
+    }
+    for (name, summary) in [
+        ("lowpass", "low-pass filter"),
+        ("highpass", "high-pass filter"),
+        ("bandpass", "band-pass filter"),
+        ("notch", "notch filter"),
+        ("moog", "Moog-style ladder filter"),
+    ] {
+        commands.register(CommandInfo {
+            namespace: "filter",
+            name,
+            summary,
+            args: &["cutoff", "q"],
+        });
+    }
+}
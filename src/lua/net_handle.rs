@@ -0,0 +1,76 @@
+//! `NetHandle`: Lua access to editing a live `fundsp` `Net`'s node graph
+//! directly — connecting and disconnecting ports, removing nodes — rather
+//! than only swapping the whole net at once via `net_replace`.
+//!
+//! `Net` addresses nodes by an opaque [`NodeId`] handed back from
+//! [`Net::push`], not by a bare index, so callers here pass back whatever
+//! userdata originally carried that `NodeId` rather than an integer.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use fundsp::net::{Net, NodeId};
+use mlua::{AnyUserData, UserData, UserDataMethods};
+
+pub type SharedNet = Rc<RefCell<Net>>;
+
+/// A `Net` node handle, as returned by whatever pushed the node into the
+/// net, and passed back into `connect`/`disconnect`/`remove` as plain
+/// userdata, since `Net` itself only knows nodes by this opaque id, not
+/// by a bare integer.
+#[derive(Clone, Copy)]
+pub struct NetNodeId(pub NodeId);
+
+impl UserData for NetNodeId {}
+
+fn node_id(userdata: &AnyUserData) -> mlua::Result<NodeId> {
+    Ok(userdata.borrow::<NetNodeId>()?.0)
+}
+
+#[derive(Clone)]
+pub struct NetHandle {
+    pub net: SharedNet,
+}
+
+impl NetHandle {
+    pub fn new(net: SharedNet) -> Self {
+        Self { net }
+    }
+
+    fn borrow(&self) -> mlua::Result<std::cell::RefMut<'_, Net>> {
+        self.net
+            .try_borrow_mut()
+            .map_err(|e| mlua::Error::external(format!("net is busy: {e}")))
+    }
+}
+
+impl UserData for NetHandle {
+    fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_method(
+            "connect",
+            |_,
+             this,
+             (source, source_port, target, target_port): (
+                AnyUserData,
+                usize,
+                AnyUserData,
+                usize,
+            )| {
+                this.borrow()?
+                    .connect(node_id(&source)?, source_port, node_id(&target)?, target_port);
+                Ok(())
+            },
+        );
+        methods.add_method(
+            "disconnect",
+            |_, this, (target, target_port): (AnyUserData, usize)| {
+                this.borrow()?.disconnect(node_id(&target)?, target_port);
+                Ok(())
+            },
+        );
+        methods.add_method("remove", |_, this, node: AnyUserData| {
+            this.borrow()?.remove(node_id(&node)?);
+            Ok(())
+        });
+    }
+}
@@ -0,0 +1,71 @@
+//! `NodeHandle`: a typed userdata reference to a live [`DspModule`], with
+//! filter methods attached directly (`node:lowpass(800, 1.0)`).
+//!
+//! Earlier bindings (`Noise.White()`, the free-function `Filter` table)
+//! passed bare registry indices back to Lua and re-looked them up on
+//! every call — easy to get wrong since nothing stopped a script from
+//! passing an index from the wrong registry, or an out-of-range int.
+//! `NodeHandle` instead carries its registry with it, so the index is
+//! only ever valid in the context it came from.
+
+use mlua::{UserData, UserDataMethods};
+
+use crate::dsp::FilterType;
+
+use super::NodeRegistry;
+
+#[derive(Clone)]
+pub struct NodeHandle {
+    pub index: usize,
+    pub nodes: NodeRegistry,
+}
+
+impl NodeHandle {
+    pub fn new(index: usize, nodes: NodeRegistry) -> Self {
+        Self { index, nodes }
+    }
+
+    fn apply(&self, filter: FilterType) -> mlua::Result<()> {
+        // `try_borrow_mut` rather than `borrow_mut`: a script that (directly
+        // or via a callback) re-enters the registry while it's already
+        // borrowed should get a normal Lua error, not abort the process.
+        let mut nodes = self
+            .nodes
+            .try_borrow_mut()
+            .map_err(|e| mlua::Error::external(format!("node registry busy: {e}")))?;
+        if self.index >= nodes.len() {
+            return Err(mlua::Error::external(format!(
+                "no such node handle {}",
+                self.index
+            )));
+        }
+        let node = nodes.remove(self.index);
+        nodes.insert(self.index, node.apply_filter(filter));
+        Ok(())
+    }
+}
+
+impl UserData for NodeHandle {
+    fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_method("lowpass", |_, this, (cutoff, q): (f64, f64)| {
+            this.apply(FilterType::Lowpass { cutoff, q })?;
+            Ok(this.clone())
+        });
+        methods.add_method("highpass", |_, this, (cutoff, q): (f64, f64)| {
+            this.apply(FilterType::Highpass { cutoff, q })?;
+            Ok(this.clone())
+        });
+        methods.add_method("bandpass", |_, this, (cutoff, q): (f64, f64)| {
+            this.apply(FilterType::Bandpass { cutoff, q })?;
+            Ok(this.clone())
+        });
+        methods.add_method("notch", |_, this, (cutoff, q): (f64, f64)| {
+            this.apply(FilterType::Notch { cutoff, q })?;
+            Ok(this.clone())
+        });
+        methods.add_method("moog", |_, this, (cutoff, q): (f64, f64)| {
+            this.apply(FilterType::Moog { cutoff, q })?;
+            Ok(this.clone())
+        });
+    }
+}
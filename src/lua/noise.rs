@@ -0,0 +1,55 @@
+//! The `Noise` table: `Noise.White()`, `Noise.Pink()`, `Noise.Brown()`,
+//! `Noise.Impulse(freq)`. Each call builds the node immediately and
+//! returns a [`NodeHandle`] pointing at it.
+
+use mlua::{Lua, Table};
+
+use crate::dsp::{DspModule, NodeType, NoiseType};
+
+use super::node_handle::NodeHandle;
+use super::NodeRegistry;
+
+pub fn register(lua: &Lua, nodes: NodeRegistry) -> anyhow::Result<()> {
+    let table: Table = lua.create_table()?;
+
+    macro_rules! noise_fn {
+        ($name:literal, $kind:expr) => {{
+            let nodes = nodes.clone();
+            table.set(
+                $name,
+                lua.create_function(move |_, ()| {
+                    let node = DspModule::build(&NodeType::Noise($kind))
+                        .map_err(mlua::Error::external)?;
+                    let mut registry = nodes
+                        .try_borrow_mut()
+                        .map_err(|e| mlua::Error::external(format!("node registry busy: {e}")))?;
+                    registry.push(node);
+                    Ok(NodeHandle::new(registry.len() - 1, nodes.clone()))
+                })?,
+            )?;
+        }};
+    }
+
+    noise_fn!("White", NoiseType::White);
+    noise_fn!("Pink", NoiseType::Pink);
+    noise_fn!("Brown", NoiseType::Brown);
+
+    {
+        let nodes = nodes.clone();
+        table.set(
+            "Impulse",
+            lua.create_function(move |_, freq: f64| {
+                let node = DspModule::build(&NodeType::Noise(NoiseType::Impulse { freq }))
+                    .map_err(mlua::Error::external)?;
+                let mut registry = nodes
+                    .try_borrow_mut()
+                    .map_err(|e| mlua::Error::external(format!("node registry busy: {e}")))?;
+                registry.push(node);
+                Ok(NodeHandle::new(registry.len() - 1, nodes.clone()))
+            })?,
+        )?;
+    }
+
+    lua.globals().set("Noise", table)?;
+    Ok(())
+}
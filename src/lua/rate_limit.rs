@@ -0,0 +1,75 @@
+//! Rate limiting and debouncing for commands issued from Lua, so a patch
+//! bug (e.g. a tight loop calling `Noise.White()`) can't spam node
+//! creation or flood downstream systems like OSC.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// A token-bucket limiter per command name, plus debouncing of identical
+/// calls that land within `debounce` of each other.
+pub struct RateLimiter {
+    max_per_second: f64,
+    debounce: Duration,
+    buckets: HashMap<String, Bucket>,
+}
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+    last_call: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(max_per_second: f64, debounce: Duration) -> Self {
+        Self {
+            max_per_second,
+            debounce,
+            buckets: HashMap::new(),
+        }
+    }
+
+    /// Returns `true` if `command` may run now, consuming a token if so.
+    pub fn allow(&mut self, command: &str) -> bool {
+        let now = Instant::now();
+        let bucket = self.buckets.entry(command.to_string()).or_insert(Bucket {
+            tokens: self.max_per_second,
+            last_refill: now,
+            last_call: now - self.debounce,
+        });
+
+        if now.duration_since(bucket.last_call) < self.debounce {
+            return false;
+        }
+
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.max_per_second).min(self.max_per_second);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            bucket.last_call = now;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debounce_rejects_rapid_repeats() {
+        let mut limiter = RateLimiter::new(1000.0, Duration::from_millis(50));
+        assert!(limiter.allow("noise:white"));
+        assert!(!limiter.allow("noise:white"));
+    }
+
+    #[test]
+    fn bucket_depletes_under_sustained_calls() {
+        let mut limiter = RateLimiter::new(1.0, Duration::ZERO);
+        assert!(limiter.allow("noise:white"));
+        assert!(!limiter.allow("noise:white"));
+    }
+}
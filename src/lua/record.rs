@@ -0,0 +1,64 @@
+//! The `Record` table: `Record.Start("take1.wav")` begins tapping the
+//! master output to a WAV file on a background thread; `Record.Stop()`
+//! flushes and closes it. Starting a new take while one is already
+//! running stops the old one first, the same way `KeysModule::on_press`
+//! treats re-registering a key as "this replaces what was there".
+//!
+//! The sample rate and channel count below are hardcoded rather than
+//! taken from a running [`crate::audio::AudioModule`]; nothing passes the
+//! output backend's settings to this table.
+
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use mlua::{Lua, Table};
+
+use crate::record::Recorder;
+
+const SAMPLE_RATE: u32 = 48_000;
+const CHANNELS: u16 = 2;
+
+pub type SharedRecorder = Rc<RefCell<Option<Recorder>>>;
+
+pub fn register(lua: &Lua, recorder: SharedRecorder) -> anyhow::Result<()> {
+    let table: Table = lua.create_table()?;
+
+    {
+        let recorder = recorder.clone();
+        table.set(
+            "Start",
+            lua.create_function(move |_, path: String| {
+                let mut slot = recorder
+                    .try_borrow_mut()
+                    .map_err(|e| mlua::Error::external(format!("recorder is busy: {e}")))?;
+                if let Some(mut previous) = slot.take() {
+                    let _ = previous.stop();
+                }
+                let new_recorder = Recorder::start(&PathBuf::from(path), SAMPLE_RATE, CHANNELS)
+                    .map_err(mlua::Error::external)?;
+                *slot = Some(new_recorder);
+                Ok(())
+            })?,
+        )?;
+    }
+    {
+        let recorder = recorder.clone();
+        table.set(
+            "Stop",
+            lua.create_function(move |_, ()| {
+                let taken = recorder
+                    .try_borrow_mut()
+                    .map_err(|e| mlua::Error::external(format!("recorder is busy: {e}")))?
+                    .take();
+                if let Some(mut recorder) = taken {
+                    recorder.stop().map_err(mlua::Error::external)?;
+                }
+                Ok(())
+            })?,
+        )?;
+    }
+
+    lua.globals().set("Record", table)?;
+    Ok(())
+}
@@ -0,0 +1,56 @@
+//! The `require` global: `require("modules/foo")` loads
+//! `<project>/modules/foo.lua`, evaluates it once, and caches the result
+//! so later `require`s of the same name are free.
+//!
+//! This mirrors stock Lua's `require`/`package.loaded`: a module that
+//! returns nothing caches (and returns) `true` rather than `nil`, so a
+//! cache hit can still be told apart from "never loaded".
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use mlua::{Lua, Table, Value};
+
+pub fn register(lua: &Lua, project_root: PathBuf) -> anyhow::Result<()> {
+    let cache: Table = lua.create_table()?;
+    let loading: Rc<RefCell<HashSet<String>>> = Rc::new(RefCell::new(HashSet::new()));
+
+    let require_fn = lua.create_function(move |lua, name: String| {
+        let cached: Value = cache.get(name.clone())?;
+        if !matches!(cached, Value::Nil) {
+            return Ok(cached);
+        }
+
+        if !loading.borrow_mut().insert(name.clone()) {
+            return Err(mlua::Error::external(format!(
+                "circular require of \"{name}\""
+            )));
+        }
+
+        let path = project_root.join(format!("{name}.lua"));
+        let load_result = std::fs::read_to_string(&path)
+            .map_err(|e| mlua::Error::external(format!("cannot require \"{name}\": {e}")))
+            .and_then(|source| {
+                lua.load(&source)
+                    .set_name(path.display().to_string())
+                    .eval::<Value>()
+            });
+
+        loading.borrow_mut().remove(&name);
+        let value = load_result?;
+
+        // A module that returns nothing still counts as loaded.
+        let stored = if matches!(value, Value::Nil) {
+            Value::Boolean(true)
+        } else {
+            value
+        };
+        cache.set(name, stored.clone())?;
+        Ok(stored)
+    })?;
+
+    lua.globals().set("require", require_fn)?;
+    Ok(())
+}
@@ -0,0 +1,23 @@
+//! The `Samples` table: `Samples.kick` returns the path to a project
+//! sample by name, for scripts to pass on to whatever plays samples.
+//! Loading the actual audio data happens lazily, on the Rust side, once a
+//! sample is actually triggered — most scripts reference far more samples
+//! than they play in any given run.
+
+use mlua::{Lua, Table};
+
+use crate::project::Project;
+
+pub fn register(lua: &Lua, project: &Project) -> anyhow::Result<()> {
+    let table: Table = lua.create_table()?;
+    for name in project.sample_names()? {
+        let path = project
+            .samples_dir()
+            .join(format!("{name}.wav"))
+            .to_string_lossy()
+            .into_owned();
+        table.set(name, path)?;
+    }
+    lua.globals().set("Samples", table)?;
+    Ok(())
+}
@@ -0,0 +1,22 @@
+//! The `Song` table: `Song.SetLength(beats)` gives an offline render an
+//! explicit end point, as an alternative to the script defining
+//! `_G.EndSong()` for the render loop to poll instead.
+
+use mlua::{Lua, Table};
+
+use crate::song::SongLength;
+
+pub fn register(lua: &Lua, length: SongLength) -> anyhow::Result<()> {
+    let table: Table = lua.create_table()?;
+
+    table.set(
+        "SetLength",
+        lua.create_function(move |_, beats: f64| {
+            length.set(Some(beats));
+            Ok(())
+        })?,
+    )?;
+
+    lua.globals().set("Song", table)?;
+    Ok(())
+}
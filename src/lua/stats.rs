@@ -0,0 +1,31 @@
+//! The `Stats` table: `Stats.audio()` returns the render-time/xrun
+//! counters an [`crate::audio::AudioModule`] has been accumulating, as a
+//! Lua table `{buffers, xruns, mean_render_ms, max_render_ms}`.
+//!
+//! [`crate::lua::install`] registers a fresh `AudioStats` here, while
+//! each `AudioModule::init` creates its own, so no buffers are ever
+//! counted on this handle and `Stats.audio()` reports all zeros.
+
+use mlua::{Lua, Table};
+
+use crate::audio::AudioStats;
+
+pub fn register(lua: &Lua, stats: AudioStats) -> anyhow::Result<()> {
+    let table: Table = lua.create_table()?;
+
+    table.set(
+        "audio",
+        lua.create_function(move |lua, ()| {
+            let snapshot = stats.snapshot();
+            let result: Table = lua.create_table()?;
+            result.set("buffers", snapshot.buffers)?;
+            result.set("xruns", snapshot.xruns)?;
+            result.set("mean_render_ms", snapshot.mean_render.as_secs_f64() * 1000.0)?;
+            result.set("max_render_ms", snapshot.max_render.as_secs_f64() * 1000.0)?;
+            Ok(result)
+        })?,
+    )?;
+
+    lua.globals().set("Stats", table)?;
+    Ok(())
+}
@@ -0,0 +1,25 @@
+//! The `Tempo` table: `Tempo.RampTo(bpm, beats)` interpolates the Timer
+//! module's BPM to `bpm` over the next `beats` beats, for accelerandos
+//! and ritardandos, instead of the hard jump `Timer.set_bpm` makes.
+
+use mlua::{Lua, Table};
+
+use crate::timer::SharedTimer;
+
+pub fn register(lua: &Lua, timer: SharedTimer) -> anyhow::Result<()> {
+    let table: Table = lua.create_table()?;
+
+    table.set(
+        "RampTo",
+        lua.create_function(move |_, (bpm, beats): (f64, f64)| {
+            timer
+                .try_borrow_mut()
+                .map_err(|e| mlua::Error::external(format!("timer is busy: {e}")))?
+                .ramp_to(bpm, beats);
+            Ok(())
+        })?,
+    )?;
+
+    lua.globals().set("Tempo", table)?;
+    Ok(())
+}
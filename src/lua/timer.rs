@@ -0,0 +1,113 @@
+//! The `Timer` table: `Timer.every(beats, fn)` schedules a repeating
+//! callback against musical time, optionally stopping after a
+//! `repeat_count` number of firings; `Timer.after(beats, fn)` and
+//! `Timer.once(fn)` are one-shot sugar over the same mechanism. Every
+//! scheduling function takes an optional trailing `priority` (default
+//! `0`, lower fires first) so same-beat callbacks fire in a predictable
+//! order, and returns an id that `Timer.cancel(id)` can later stop.
+//! `Timer.set_swing(amount)` pushes every other firing of every
+//! registered callback back by `amount` of its interval.
+
+use mlua::{Function, Lua, Table};
+
+use crate::timer::SharedTimer;
+
+pub fn register(lua: &Lua, timer: SharedTimer) -> anyhow::Result<()> {
+    let table: Table = lua.create_table()?;
+
+    {
+        let timer = timer.clone();
+        table.set(
+            "every",
+            lua.create_function(
+                move |lua,
+                      (beats, func, repeat_count, priority): (
+                    f64,
+                    Function,
+                    Option<u32>,
+                    Option<i32>,
+                )| {
+                    let mut timer = timer
+                        .try_borrow_mut()
+                        .map_err(|e| mlua::Error::external(format!("timer is busy: {e}")))?;
+                    let priority = priority.unwrap_or(0);
+                    let result = match repeat_count {
+                        Some(count) => timer.add_limited(lua, beats, count, priority, func),
+                        None => timer.add_callback(lua, beats, priority, func),
+                    };
+                    result.map_err(mlua::Error::external)
+                },
+            )?,
+        )?;
+    }
+    {
+        let timer = timer.clone();
+        table.set(
+            "after",
+            lua.create_function(
+                move |lua, (beats, func, priority): (f64, Function, Option<i32>)| {
+                    timer
+                        .try_borrow_mut()
+                        .map_err(|e| mlua::Error::external(format!("timer is busy: {e}")))?
+                        .add_once(lua, beats, priority.unwrap_or(0), func)
+                        .map_err(mlua::Error::external)
+                },
+            )?,
+        )?;
+    }
+    {
+        let timer = timer.clone();
+        table.set(
+            "once",
+            lua.create_function(move |lua, (func, priority): (Function, Option<i32>)| {
+                timer
+                    .try_borrow_mut()
+                    .map_err(|e| mlua::Error::external(format!("timer is busy: {e}")))?
+                    .add_once(lua, 0.0, priority.unwrap_or(0), func)
+                    .map_err(mlua::Error::external)
+            })?,
+        )?;
+    }
+    {
+        let timer = timer.clone();
+        table.set(
+            "cancel",
+            lua.create_function(move |_, id: u64| {
+                timer
+                    .try_borrow_mut()
+                    .map_err(|e| mlua::Error::external(format!("timer is busy: {e}")))?
+                    .cancel(id);
+                Ok(())
+            })?,
+        )?;
+    }
+    {
+        let timer = timer.clone();
+        table.set(
+            "set_swing",
+            lua.create_function(move |_, amount: f64| {
+                timer
+                    .try_borrow_mut()
+                    .map_err(|e| mlua::Error::external(format!("timer is busy: {e}")))?
+                    .set_swing(amount);
+                Ok(())
+            })?,
+        )?;
+    }
+    {
+        let timer = timer.clone();
+        table.set(
+            "set_bpm",
+            lua.create_function(move |_, bpm: f64| {
+                timer
+                    .try_borrow_mut()
+                    .map_err(|e| mlua::Error::external(format!("timer is busy: {e}")))?
+                    .set_bpm(bpm);
+                Ok(())
+            })?,
+        )?;
+    }
+
+    lua.globals().set("Timer", table)?;
+    Ok(())
+}
@@ -0,0 +1,106 @@
+//! The `Transport` table: `Transport.play()`, `.pause()`, `.seek(t)`,
+//! `.set_loop(a, b)`, `.clear_loop()`, `.position()`, `.is_playing()` —
+//! Lua-side control over the song position the native run loop advances.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use mlua::{Lua, Table};
+
+use crate::transport::Transport;
+
+pub type SharedTransport = Rc<RefCell<Transport>>;
+
+pub fn register(lua: &Lua, transport: SharedTransport) -> anyhow::Result<()> {
+    let table: Table = lua.create_table()?;
+
+    {
+        let transport = transport.clone();
+        table.set(
+            "play",
+            lua.create_function(move |_, ()| {
+                transport
+                    .try_borrow_mut()
+                    .map_err(|e| mlua::Error::external(format!("transport is busy: {e}")))?
+                    .play();
+                Ok(())
+            })?,
+        )?;
+    }
+    {
+        let transport = transport.clone();
+        table.set(
+            "pause",
+            lua.create_function(move |_, ()| {
+                transport
+                    .try_borrow_mut()
+                    .map_err(|e| mlua::Error::external(format!("transport is busy: {e}")))?
+                    .pause();
+                Ok(())
+            })?,
+        )?;
+    }
+    {
+        let transport = transport.clone();
+        table.set(
+            "seek",
+            lua.create_function(move |_, position: f64| {
+                transport
+                    .try_borrow_mut()
+                    .map_err(|e| mlua::Error::external(format!("transport is busy: {e}")))?
+                    .seek(position);
+                Ok(())
+            })?,
+        )?;
+    }
+    {
+        let transport = transport.clone();
+        table.set(
+            "set_loop",
+            lua.create_function(move |_, (start, end): (f64, f64)| {
+                transport
+                    .try_borrow_mut()
+                    .map_err(|e| mlua::Error::external(format!("transport is busy: {e}")))?
+                    .set_loop(start, end);
+                Ok(())
+            })?,
+        )?;
+    }
+    {
+        let transport = transport.clone();
+        table.set(
+            "clear_loop",
+            lua.create_function(move |_, ()| {
+                transport
+                    .try_borrow_mut()
+                    .map_err(|e| mlua::Error::external(format!("transport is busy: {e}")))?
+                    .clear_loop();
+                Ok(())
+            })?,
+        )?;
+    }
+    {
+        let transport = transport.clone();
+        table.set(
+            "position",
+            lua.create_function(move |_, ()| {
+                Ok(transport
+                    .try_borrow()
+                    .map_err(|e| mlua::Error::external(format!("transport is busy: {e}")))?
+                    .position())
+            })?,
+        )?;
+    }
+    table.set(
+        "is_playing",
+        lua.create_function(move |_, ()| {
+            Ok(transport
+                .try_borrow()
+                .map_err(|e| mlua::Error::external(format!("transport is busy: {e}")))?
+                .is_playing())
+        })?,
+    )?;
+
+    lua.globals().set("Transport", table)?;
+    Ok(())
+}
@@ -0,0 +1,102 @@
+//! Input mapping profiles: naming which MIDI CC numbers and notes mean
+//! what for a given controller, so switching hardware doesn't mean
+//! rewriting the project script.
+//!
+//! Profiles are plain `key=value` text files, one mapping per line, in
+//! the same ad-hoc style as [`crate::project::Project::output_overrides`]
+//! until profiles grow complex enough to need `octmm.toml` support.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+/// What a mapped control drives. `Custom` covers anything a project's own
+/// Lua code wants to interpret, keyed by a name it chooses itself.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MappingTarget {
+    MasterVolume,
+    Custom(String),
+}
+
+impl MappingTarget {
+    fn parse(value: &str) -> Self {
+        match value {
+            "master_volume" => MappingTarget::MasterVolume,
+            other => MappingTarget::Custom(other.to_string()),
+        }
+    }
+}
+
+/// A named controller preset: which CC numbers and note numbers map to
+/// which targets.
+#[derive(Debug, Clone, Default)]
+pub struct ControllerProfile {
+    pub name: String,
+    cc_map: HashMap<u8, MappingTarget>,
+    note_map: HashMap<u8, MappingTarget>,
+}
+
+impl ControllerProfile {
+    /// Loads a profile from a `key=value` file, where keys are
+    /// `cc:<number>` or `note:<number>`.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let contents = std::fs::read_to_string(path)?;
+        let mut profile = ControllerProfile {
+            name,
+            ..Default::default()
+        };
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let (key, value) = (key.trim(), value.trim());
+            if let Some(number) = key.strip_prefix("cc:") {
+                if let Ok(cc) = number.parse() {
+                    profile.cc_map.insert(cc, MappingTarget::parse(value));
+                }
+            } else if let Some(number) = key.strip_prefix("note:") {
+                if let Ok(note) = number.parse() {
+                    profile.note_map.insert(note, MappingTarget::parse(value));
+                }
+            }
+        }
+        Ok(profile)
+    }
+
+    pub fn target_for_cc(&self, controller: u8) -> Option<&MappingTarget> {
+        self.cc_map.get(&controller)
+    }
+
+    pub fn target_for_note(&self, note: u8) -> Option<&MappingTarget> {
+        self.note_map.get(&note)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_cc_and_note_mappings() {
+        let path = std::env::temp_dir().join("octmm-mapping-test.profile");
+        std::fs::write(&path, "cc:7=master_volume\nnote:36=kick\n# a comment\n").unwrap();
+
+        let profile = ControllerProfile::load(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(profile.target_for_cc(7), Some(&MappingTarget::MasterVolume));
+        assert_eq!(
+            profile.target_for_note(36),
+            Some(&MappingTarget::Custom("kick".to_string()))
+        );
+        assert_eq!(profile.target_for_cc(1), None);
+    }
+}
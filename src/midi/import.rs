@@ -0,0 +1,53 @@
+//! Importing a Standard MIDI File's first note track into a
+//! [`Sequence`], quantized to a fixed step grid.
+
+use std::path::Path;
+
+use midly::{MetaMessage, MidiMessage, Smf, TrackEventKind};
+
+use crate::note::MidiNote;
+use crate::sequence::{Sequence, Step};
+
+/// Reads `path` and quantizes the first track with note-on events to
+/// `steps_per_beat` steps per beat, returning a [`Sequence`] long enough
+/// to hold the last note in that track.
+pub fn import(path: &Path, steps_per_beat: u32) -> anyhow::Result<Sequence> {
+    let bytes = std::fs::read(path)?;
+    let smf = Smf::parse(&bytes)?;
+
+    let ticks_per_beat = match smf.header.timing {
+        midly::Timing::Metrical(t) => t.as_int() as u32,
+        midly::Timing::Timecode(..) => anyhow::bail!("SMPTE-timed MIDI files aren't supported"),
+    };
+    let ticks_per_step = ticks_per_beat / steps_per_beat.max(1);
+
+    let mut notes: Vec<(u32, MidiNote)> = Vec::new();
+    for track in &smf.tracks {
+        let mut tick: u32 = 0;
+        let mut found_note = false;
+        for event in track {
+            tick += event.delta.as_int();
+            match event.kind {
+                TrackEventKind::Midi {
+                    message: MidiMessage::NoteOn { key, vel },
+                    ..
+                } if vel.as_int() > 0 => {
+                    notes.push((tick / ticks_per_step.max(1), MidiNote(key.as_int())));
+                    found_note = true;
+                }
+                TrackEventKind::Meta(MetaMessage::EndOfTrack) if found_note => break,
+                _ => {}
+            }
+        }
+        if found_note {
+            break;
+        }
+    }
+
+    let len = notes.iter().map(|(step, _)| step + 1).max().unwrap_or(0) as usize;
+    let mut sequence = Sequence::blank(len.max(1));
+    for (step, note) in notes {
+        sequence.set_step(step as usize, Step::Note(note));
+    }
+    Ok(sequence)
+}
@@ -0,0 +1,61 @@
+//! Opens a MIDI input port and forwards parsed events through a channel,
+//! since `midir`'s callback runs on its own thread.
+
+use std::sync::mpsc::{self, Receiver};
+
+use midir::{Ignore, MidiInput as MidirInput, MidiInputConnection};
+
+use super::{parse_message, MidiEvent};
+
+/// Owns the live MIDI connection; dropping this closes the port.
+pub struct MidiInput {
+    _connection: MidiInputConnection<()>,
+    events: Receiver<MidiEvent>,
+}
+
+impl MidiInput {
+    /// Connects to the first input port whose name contains `port_hint`
+    /// (case-insensitive), or the first available port if `port_hint` is
+    /// empty.
+    pub fn connect(port_hint: &str) -> anyhow::Result<Self> {
+        let mut midi_in = MidirInput::new("octmm")?;
+        midi_in.ignore(Ignore::None);
+
+        let ports = midi_in.ports();
+        let port = ports
+            .iter()
+            .find(|p| {
+                midi_in
+                    .port_name(p)
+                    .map(|name| {
+                        port_hint.is_empty() || name.to_lowercase().contains(&port_hint.to_lowercase())
+                    })
+                    .unwrap_or(false)
+            })
+            .ok_or_else(|| anyhow::anyhow!("no MIDI input port matching '{port_hint}'"))?;
+
+        let (tx, rx) = mpsc::channel();
+        let connection = midi_in
+            .connect(
+                port,
+                "octmm-input",
+                move |_timestamp, message, _| {
+                    if let Some(event) = parse_message(message) {
+                        let _ = tx.send(event);
+                    }
+                },
+                (),
+            )
+            .map_err(|e| anyhow::anyhow!("failed to open MIDI port: {e}"))?;
+
+        Ok(Self {
+            _connection: connection,
+            events: rx,
+        })
+    }
+
+    /// Drains every event received since the last call.
+    pub fn poll(&self) -> Vec<MidiEvent> {
+        self.events.try_iter().collect()
+    }
+}
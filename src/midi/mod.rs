@@ -0,0 +1,39 @@
+//! MIDI input for live performance: listens on a hardware/virtual MIDI
+//! port and turns note-on/note-off messages into [`MidiEvent`]s the
+//! runner can dispatch to a sequence or instrument.
+
+pub mod import;
+pub mod input;
+pub mod output;
+
+use crate::note::MidiNote;
+
+#[derive(Debug, Clone, Copy)]
+pub enum MidiEvent {
+    NoteOn { note: MidiNote, velocity: u8 },
+    NoteOff { note: MidiNote },
+    /// MIDI CC messages, for mapping a controller knob to a parameter.
+    ControlChange { controller: u8, value: u8 },
+}
+
+/// Parses a raw MIDI message (as delivered by `midir`) into a
+/// [`MidiEvent`], ignoring message types we don't act on yet.
+pub fn parse_message(bytes: &[u8]) -> Option<MidiEvent> {
+    let status = *bytes.first()?;
+    let kind = status & 0xF0;
+    match kind {
+        0x90 if bytes.get(2).copied().unwrap_or(0) > 0 => Some(MidiEvent::NoteOn {
+            note: MidiNote(*bytes.get(1)?),
+            velocity: *bytes.get(2)?,
+        }),
+        // A note-on with velocity 0 is conventionally a note-off.
+        0x90 | 0x80 => Some(MidiEvent::NoteOff {
+            note: MidiNote(*bytes.get(1)?),
+        }),
+        0xB0 => Some(MidiEvent::ControlChange {
+            controller: *bytes.get(1)?,
+            value: *bytes.get(2)?,
+        }),
+        _ => None,
+    }
+}
@@ -0,0 +1,65 @@
+//! Opens a MIDI output port so OCTMM can drive external synths and
+//! hardware, the mirror image of [`super::input`].
+
+use midir::{MidiOutput as MidirOutput, MidiOutputConnection};
+
+use crate::note::MidiNote;
+
+use super::MidiEvent;
+
+/// Owns the live MIDI output connection; dropping this closes the port.
+pub struct MidiOutput {
+    connection: MidiOutputConnection,
+}
+
+impl MidiOutput {
+    /// Connects to the first output port whose name contains `port_hint`
+    /// (case-insensitive), or the first available port if `port_hint` is
+    /// empty.
+    pub fn connect(port_hint: &str) -> anyhow::Result<Self> {
+        let midi_out = MidirOutput::new("octmm")?;
+
+        let ports = midi_out.ports();
+        let port = ports
+            .iter()
+            .find(|p| {
+                midi_out
+                    .port_name(p)
+                    .map(|name| {
+                        port_hint.is_empty() || name.to_lowercase().contains(&port_hint.to_lowercase())
+                    })
+                    .unwrap_or(false)
+            })
+            .ok_or_else(|| anyhow::anyhow!("no MIDI output port matching '{port_hint}'"))?;
+
+        let connection = midi_out
+            .connect(port, "octmm-output")
+            .map_err(|e| anyhow::anyhow!("failed to open MIDI port: {e}"))?;
+
+        Ok(Self { connection })
+    }
+
+    pub fn send(&mut self, event: MidiEvent) -> anyhow::Result<()> {
+        let message = encode_message(event);
+        self.connection.send(&message)?;
+        Ok(())
+    }
+
+    pub fn note_on(&mut self, note: MidiNote, velocity: u8) -> anyhow::Result<()> {
+        self.send(MidiEvent::NoteOn { note, velocity })
+    }
+
+    pub fn note_off(&mut self, note: MidiNote) -> anyhow::Result<()> {
+        self.send(MidiEvent::NoteOff { note })
+    }
+}
+
+/// The inverse of [`super::parse_message`]: always emits on MIDI channel 0,
+/// since OCTMM doesn't yet have a concept of per-voice output channels.
+fn encode_message(event: MidiEvent) -> [u8; 3] {
+    match event {
+        MidiEvent::NoteOn { note, velocity } => [0x90, note.0, velocity],
+        MidiEvent::NoteOff { note } => [0x80, note.0, 0],
+        MidiEvent::ControlChange { controller, value } => [0xB0, controller, value],
+    }
+}
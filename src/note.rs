@@ -0,0 +1,65 @@
+//! Pitch utilities: scientific pitch notation (`"A4"`, `"C#3"`) and MIDI
+//! note numbers, both convertible to frequency in Hz.
+
+const NOTE_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// A MIDI note number, 0-127, where 69 is A4 (440 Hz).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MidiNote(pub u8);
+
+impl MidiNote {
+    pub fn to_freq(self) -> f64 {
+        440.0 * 2f64.powf((self.0 as f64 - 69.0) / 12.0)
+    }
+
+    pub fn to_scientific_pitch(self) -> String {
+        let name = NOTE_NAMES[self.0 as usize % 12];
+        let octave = (self.0 as i32 / 12) - 1;
+        format!("{name}{octave}")
+    }
+
+    /// Parses scientific pitch notation such as `"A4"` or `"C#3"`.
+    pub fn parse(pitch: &str) -> anyhow::Result<Self> {
+        let (name, rest) = split_name_and_octave(pitch)
+            .ok_or_else(|| anyhow::anyhow!("not a valid pitch: {pitch}"))?;
+        let index = NOTE_NAMES
+            .iter()
+            .position(|n| n.eq_ignore_ascii_case(name))
+            .ok_or_else(|| anyhow::anyhow!("unknown note name: {name}"))?;
+        let octave: i32 = rest
+            .parse()
+            .map_err(|_| anyhow::anyhow!("invalid octave in pitch: {pitch}"))?;
+        let midi = (octave + 1) * 12 + index as i32;
+        if !(0..=127).contains(&midi) {
+            anyhow::bail!("pitch {pitch} is out of MIDI range");
+        }
+        Ok(MidiNote(midi as u8))
+    }
+}
+
+fn split_name_and_octave(pitch: &str) -> Option<(&str, &str)> {
+    let split_at = pitch
+        .char_indices()
+        .find(|(i, c)| c.is_ascii_digit() || (*i > 0 && *c == '-'))
+        .map(|(i, _)| i)?;
+    Some((&pitch[..split_at], &pitch[split_at..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a4_is_440hz() {
+        assert_eq!(MidiNote::parse("A4").unwrap(), MidiNote(69));
+        assert!((MidiNote(69).to_freq() - 440.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn round_trips_scientific_pitch() {
+        let note = MidiNote::parse("C#3").unwrap();
+        assert_eq!(note.to_scientific_pitch(), "C#3");
+    }
+}
@@ -0,0 +1,38 @@
+//! Raw OSC message sending, and higher-level bridges built on top of it
+//! (see [`supercollider`]).
+
+pub mod server;
+pub mod supercollider;
+
+use std::net::{SocketAddr, UdpSocket};
+use std::sync::Mutex;
+
+use rosc::{OscMessage, OscPacket, OscType};
+
+/// A UDP-backed OSC client. Owns a single outbound socket; safe to share
+/// across threads since sends go through a mutex.
+pub struct OscClient {
+    socket: Mutex<UdpSocket>,
+    target: SocketAddr,
+}
+
+impl OscClient {
+    pub fn connect(target: SocketAddr) -> anyhow::Result<Self> {
+        let socket = UdpSocket::bind("0.0.0.0:0")?;
+        Ok(Self {
+            socket: Mutex::new(socket),
+            target,
+        })
+    }
+
+    /// Sends a single OSC message with the given address pattern and args.
+    pub fn send(&self, addr: &str, args: Vec<OscType>) -> anyhow::Result<()> {
+        let packet = OscPacket::Message(OscMessage {
+            addr: addr.to_string(),
+            args,
+        });
+        let buf = rosc::encoder::encode(&packet)?;
+        self.socket.lock().unwrap().send_to(&buf, self.target)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+//! Listening for incoming OSC messages, for network control of a running
+//! project (a touch-OSC layout, a lighting console, another instance of
+//! OCTMM). Mirrors [`super::OscClient`] but in the receive direction.
+
+use std::net::UdpSocket;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+use rosc::{OscPacket, OscType};
+
+/// A received OSC message, flattened out of its packet wrapper.
+pub struct OscIncoming {
+    pub addr: String,
+    pub args: Vec<OscType>,
+}
+
+/// Owns a background thread reading OSC packets off a UDP socket and
+/// forwarding them through a channel, since the socket read blocks.
+pub struct OscServer {
+    messages: Receiver<OscIncoming>,
+}
+
+impl OscServer {
+    /// Binds to `bind_addr` (e.g. `"0.0.0.0:9000"`) and starts listening.
+    pub fn bind(bind_addr: &str) -> anyhow::Result<Self> {
+        let socket = UdpSocket::bind(bind_addr)?;
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let mut buf = [0u8; 1536];
+            loop {
+                let Ok((size, _from)) = socket.recv_from(&mut buf) else {
+                    break;
+                };
+                if let Ok((_, packet)) = rosc::decoder::decode_udp(&buf[..size]) {
+                    for message in flatten(packet) {
+                        if tx.send(message).is_err() {
+                            return;
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(Self { messages: rx })
+    }
+
+    /// Drains every message received since the last call.
+    pub fn poll(&self) -> Vec<OscIncoming> {
+        self.messages.try_iter().collect()
+    }
+}
+
+/// Bundles can nest arbitrarily deeply; this walks them into a flat list
+/// of messages in the order they were packed.
+fn flatten(packet: OscPacket) -> Vec<OscIncoming> {
+    match packet {
+        OscPacket::Message(m) => vec![OscIncoming {
+            addr: m.addr,
+            args: m.args,
+        }],
+        OscPacket::Bundle(b) => b.content.into_iter().flat_map(flatten).collect(),
+    }
+}
@@ -0,0 +1,58 @@
+//! Convenience wrappers to drive a running `scsynth` (SuperCollider server)
+//! from OCTMM sequences, so OCTMM can act purely as a sequencer front-end
+//! to synthdefs that already exist on the SuperCollider side.
+
+use rosc::OscType;
+
+use super::OscClient;
+
+/// Thin wrapper over [`OscClient`] that knows the handful of `scsynth`
+/// commands a sequencer front-end needs. Anything more exotic (buffer
+/// management, groups, bus routing) is out of scope here — reach for
+/// [`OscClient::send`] directly if you need the raw message.
+pub struct SuperCollider {
+    client: OscClient,
+}
+
+impl SuperCollider {
+    pub fn new(client: OscClient) -> Self {
+        Self { client }
+    }
+
+    /// `/s_new`: instantiate `synth_def` as node `node_id` in group
+    /// `target_group`, with the given control pairs (name, value).
+    pub fn s_new(
+        &self,
+        synth_def: &str,
+        node_id: i32,
+        target_group: i32,
+        controls: &[(&str, f32)],
+    ) -> anyhow::Result<()> {
+        let mut args = vec![
+            OscType::String(synth_def.to_string()),
+            OscType::Int(node_id),
+            OscType::Int(0), // add action: add to head
+            OscType::Int(target_group),
+        ];
+        for (name, value) in controls {
+            args.push(OscType::String(name.to_string()));
+            args.push(OscType::Float(*value));
+        }
+        self.client.send("/s_new", args)
+    }
+
+    /// `/n_set`: update control values on an already-running node.
+    pub fn n_set(&self, node_id: i32, controls: &[(&str, f32)]) -> anyhow::Result<()> {
+        let mut args = vec![OscType::Int(node_id)];
+        for (name, value) in controls {
+            args.push(OscType::String(name.to_string()));
+            args.push(OscType::Float(*value));
+        }
+        self.client.send("/n_set", args)
+    }
+
+    /// `/n_free`: release a node.
+    pub fn n_free(&self, node_id: i32) -> anyhow::Result<()> {
+        self.client.send("/n_free", vec![OscType::Int(node_id)])
+    }
+}
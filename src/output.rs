@@ -0,0 +1,105 @@
+//! Audio output device and sample rate selection, configurable from the
+//! CLI or overridden per-project.
+
+use clap::Args;
+
+#[derive(Debug, Clone, Args)]
+pub struct OutputArgs {
+    /// Output device name, as reported by `cpal`'s device list. Defaults
+    /// to the host's default output device.
+    #[arg(long)]
+    pub device: Option<String>,
+
+    /// Output sample rate in Hz. Defaults to the device's preferred rate.
+    #[arg(long)]
+    pub sample_rate: Option<u32>,
+
+    /// Host backend to open the device through (e.g. "wasapi", "asio",
+    /// "coreaudio", "alsa"), as reported by `cpal`'s host list. Defaults
+    /// to the platform's default host.
+    #[arg(long)]
+    pub host: Option<String>,
+
+    /// Requested output buffer size in frames, for trading latency
+    /// against underrun safety. Left to the device's default when unset.
+    #[arg(long)]
+    pub buffer_size: Option<u32>,
+
+    /// Request exclusive-mode access to the device where the host backend
+    /// supports it, for lower latency than shared mode allows. The cpal
+    /// backend implements exclusive mode on no host: it logs a warning and
+    /// opens the device in shared mode.
+    #[arg(long)]
+    pub exclusive: bool,
+}
+
+/// Resolved output settings, after merging CLI flags with any
+/// project-level overrides. CLI flags win when both are set.
+#[derive(Debug, Clone, Default)]
+pub struct OutputConfig {
+    pub device: Option<String>,
+    pub sample_rate: Option<u32>,
+    pub host: Option<String>,
+    pub buffer_size: Option<u32>,
+    pub exclusive: bool,
+}
+
+impl OutputConfig {
+    pub fn from_args(args: &OutputArgs, project_overrides: OutputConfig) -> Self {
+        Self {
+            device: args.device.clone().or(project_overrides.device),
+            sample_rate: args.sample_rate.or(project_overrides.sample_rate),
+            host: args.host.clone().or(project_overrides.host),
+            buffer_size: args.buffer_size.or(project_overrides.buffer_size),
+            exclusive: args.exclusive || project_overrides.exclusive,
+        }
+    }
+}
+
+/// Hard-caps output amplitude and logs a warning (at most once per second
+/// of audio) when it has to, since a runaway feedback patch driving a
+/// hardware output straight to clipping is a real hearing-safety risk,
+/// not just a sound-quality one.
+pub struct SafetyLimiter {
+    max_peak: f64,
+    sample_rate: u32,
+    samples_since_warning: u32,
+}
+
+impl SafetyLimiter {
+    pub fn new(max_peak: f64, sample_rate: u32) -> Self {
+        Self {
+            max_peak: max_peak.abs(),
+            sample_rate,
+            samples_since_warning: 0,
+        }
+    }
+
+    pub fn process(&mut self, sample: f64) -> f64 {
+        self.samples_since_warning = self.samples_since_warning.saturating_add(1);
+        if sample.abs() <= self.max_peak {
+            return sample;
+        }
+        if self.samples_since_warning >= self.sample_rate.max(1) {
+            log::warn!(
+                "output exceeded the {:.2} safety cap and was clamped",
+                self.max_peak
+            );
+            self.samples_since_warning = 0;
+        }
+        sample.clamp(-self.max_peak, self.max_peak)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamps_samples_over_the_cap() {
+        let mut limiter = SafetyLimiter::new(0.9, 48_000);
+        assert_eq!(limiter.process(0.5), 0.5);
+        assert_eq!(limiter.process(1.5), 0.9);
+        assert_eq!(limiter.process(-1.5), -0.9);
+    }
+}
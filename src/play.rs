@@ -0,0 +1,146 @@
+//! `octmm play`: loads a project and keeps it running, instead of
+//! validating it once like `octmm check` does.
+
+use std::cell::{Cell, RefCell};
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::time::Duration;
+
+use mlua::Lua;
+
+use crate::keys::KeysModule;
+use crate::lua;
+use crate::output::{OutputArgs, OutputConfig};
+use crate::project::Project;
+use crate::remote::RemoteControl;
+use crate::runner::{JitterStats, Runner};
+use crate::time::{FixedStepClock, SystemClock, TimeSource};
+use crate::timer::TimerModule;
+use crate::transport::Transport;
+use crate::tui::{self, TuiModule};
+
+#[derive(Debug, clap::Args)]
+pub struct PlayArgs {
+    /// Project directory to run.
+    pub project: PathBuf,
+
+    /// Accept Lua snippets on `127.0.0.1:<port>`, one per line, and
+    /// `exec` each one against the running session. Meant for an editor
+    /// plugin to send code to a live patch rather than for a human to
+    /// type into directly — see `octmm repl` for that.
+    #[arg(long)]
+    pub listen: Option<u16>,
+
+    /// Run with a deterministic fixed-step clock instead of wall time, so
+    /// timers fire at the same simulated moments on every run regardless
+    /// of host load. Useful for tests and (eventually) offline export.
+    #[arg(long)]
+    pub render: bool,
+
+    /// Target ticks per second. Ignored under `--turbo`, which runs ticks
+    /// flat-out; under `--render` it sets the fixed simulated step of each
+    /// tick instead of a wall-clock pace.
+    #[arg(long, default_value_t = 1000.0)]
+    pub tick_rate: f64,
+
+    /// Run ticks back-to-back with no pacing sleep at all. Can also be
+    /// turned on per-project via `[engine] turbo` in `octmm.toml`; this
+    /// flag only ever turns it on, never off.
+    #[arg(long)]
+    pub turbo: bool,
+
+    /// Replace the silent console with a terminal status display
+    /// showing elapsed time, bar/beat, BPM, CPU/tick jitter, and recent
+    /// Lua `print` output.
+    #[arg(long)]
+    pub ui: bool,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+pub fn run(args: PlayArgs) -> anyhow::Result<()> {
+    let project = Project::load(&args.project)?;
+    let lua = Lua::new();
+
+    let nodes = Default::default();
+    let transport = Rc::new(RefCell::new(Transport::default()));
+    let timer = Rc::new(RefCell::new(TimerModule::default()));
+    let keys = Rc::new(RefCell::new(KeysModule::default()));
+    lua::install(
+        &lua,
+        nodes,
+        transport.clone(),
+        timer.clone(),
+        keys.clone(),
+        Default::default(),
+    )?;
+    lua::install_project(&lua, &project)?;
+
+    let print_log = tui::PrintLog::default();
+    if args.ui {
+        tui::install_print_capture(&lua, print_log.clone())?;
+    }
+
+    for module in project.modules()? {
+        let source = std::fs::read_to_string(&module)?;
+        lua::exec_file(&lua, &module, &source)?;
+    }
+
+    let source = std::fs::read_to_string(&project.entry_script)?;
+    lua::exec_file(&lua, &project.entry_script, &source)?;
+
+    let _output = OutputConfig::from_args(&args.output, project.output_overrides()?);
+
+    let remote = match args.listen {
+        Some(port) => {
+            let remote = RemoteControl::bind(port)?;
+            log::info!("listening for remote Lua on 127.0.0.1:{port}");
+            Some(remote)
+        }
+        None => None,
+    };
+
+    let jitter: Rc<Cell<JitterStats>> = Rc::new(Cell::new(JitterStats::default()));
+    lua::install_engine(&lua, jitter.clone())?;
+
+    let turbo = args.turbo || project.turbo();
+    let sleep_duration = Duration::from_secs_f64(1.0 / args.tick_rate.max(1.0));
+
+    let clock: Box<dyn TimeSource> = if args.render {
+        Box::new(FixedStepClock::new(sleep_duration))
+    } else {
+        Box::new(SystemClock::default())
+    };
+    let mut builder = Runner::builder()
+        .with_lua(lua)
+        .with_clock(clock)
+        .with_module(Box::new(timer.clone()))
+        .with_module(Box::new(keys));
+    if args.ui {
+        builder = builder.with_module(Box::new(TuiModule::new(timer, jitter.clone(), print_log)));
+    }
+    let mut runner = builder.build();
+
+    // This loop reads neither `Song.SetLength` nor `_G.EndSong` (see
+    // `crate::song`), so `--render` runs forever like the live path does;
+    // it buys determinism, not an actual offline export.
+    loop {
+        runner.tick()?;
+        let stats = runner.jitter_stats();
+        jitter.set(stats);
+        transport.borrow_mut().advance(stats.last_dt);
+
+        if let Some(remote) = &remote {
+            for snippet in remote.poll() {
+                if let Err(e) = runner.lua().load(&snippet).set_name("remote").exec() {
+                    log::warn!("remote snippet failed: {e}");
+                }
+            }
+        }
+
+        if !args.render && !turbo {
+            std::thread::sleep(sleep_duration);
+        }
+    }
+}
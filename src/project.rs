@@ -0,0 +1,151 @@
+//! A project is a directory containing a patch script and its assets.
+//! This is intentionally thin for now; it grows alongside the Lua/DSP
+//! plumbing that actually runs a project.
+
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::output::OutputConfig;
+
+/// The shape of `octmm.toml`. Everything is optional since a project
+/// without one is just as valid as one with.
+#[derive(Debug, Default, Deserialize)]
+struct ProjectConfig {
+    #[serde(default)]
+    output: OutputConfigToml,
+    #[serde(default)]
+    engine: EngineConfigToml,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OutputConfigToml {
+    device: Option<String>,
+    sample_rate: Option<u32>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct EngineConfigToml {
+    /// Run ticks back-to-back with no pacing sleep. Usually set per
+    /// invocation via `octmm play --turbo`; `[engine] turbo = true` here
+    /// makes that the project's default.
+    #[serde(default)]
+    turbo: bool,
+}
+
+pub struct Project {
+    pub root: PathBuf,
+    pub entry_script: PathBuf,
+    config: ProjectConfig,
+}
+
+impl Project {
+    pub fn load(root: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let root = root.as_ref().to_path_buf();
+        let entry_script = root.join("main.lua");
+        if !entry_script.exists() {
+            anyhow::bail!("no main.lua found in project {}", root.display());
+        }
+
+        let config_path = root.join("octmm.toml");
+        let config = if config_path.exists() {
+            let text = std::fs::read_to_string(&config_path)?;
+            toml::from_str(&text)
+                .map_err(|e| anyhow::anyhow!("invalid {}: {e}", config_path.display()))?
+        } else {
+            ProjectConfig::default()
+        };
+
+        Ok(Self {
+            root,
+            entry_script,
+            config,
+        })
+    }
+
+    /// Directory the project's `.wav` samples live in, by convention.
+    pub fn samples_dir(&self) -> PathBuf {
+        self.root.join("samples")
+    }
+
+    /// Lists sample names (file stems) found in [`Self::samples_dir`],
+    /// without loading their audio data.
+    pub fn sample_names(&self) -> anyhow::Result<Vec<String>> {
+        let dir = self.samples_dir();
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut names = Vec::new();
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) == Some("wav") {
+                if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                    names.push(stem.to_string());
+                }
+            }
+        }
+        Ok(names)
+    }
+
+    /// Project-local Lua modules under `modules/`, in the order they
+    /// should be loaded: alphabetically by file name, so a project can
+    /// control load order just by naming files `01_foo.lua`, `02_bar.lua`.
+    /// Returns an empty list if there's no `modules/` directory.
+    pub fn modules(&self) -> anyhow::Result<Vec<PathBuf>> {
+        let dir = self.root.join("modules");
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) == Some("lua") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        Ok(paths)
+    }
+
+    /// Whether this project asks to run in turbo mode (no tick-pacing
+    /// sleep) by default. A CLI `--turbo` flag should still be able to
+    /// turn this on even when it's off here, but not the reverse.
+    pub fn turbo(&self) -> bool {
+        self.config.engine.turbo
+    }
+
+    /// Output device/sample-rate overrides for this project, if any.
+    ///
+    /// Prefers `[output]` in `octmm.toml`; falls back to the older
+    /// `output.cfg` (plain `key=value` lines) for projects that haven't
+    /// migrated yet. The two aren't merged — whichever file is present
+    /// wins outright, so a project can't end up with half its overrides
+    /// in one and half in the other.
+    pub fn output_overrides(&self) -> anyhow::Result<OutputConfig> {
+        if self.root.join("octmm.toml").exists() {
+            return Ok(OutputConfig {
+                device: self.config.output.device.clone(),
+                sample_rate: self.config.output.sample_rate,
+                ..Default::default()
+            });
+        }
+
+        let path = self.root.join("output.cfg");
+        if !path.exists() {
+            return Ok(OutputConfig::default());
+        }
+        let text = std::fs::read_to_string(path)?;
+        let mut config = OutputConfig::default();
+        for line in text.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            match key.trim() {
+                "device" => config.device = Some(value.trim().to_string()),
+                "sample_rate" => config.sample_rate = value.trim().parse().ok(),
+                _ => {}
+            }
+        }
+        Ok(config)
+    }
+}
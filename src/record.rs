@@ -0,0 +1,85 @@
+//! Live recording of the master output to disk. A [`Recorder`] owns a
+//! background thread with its own `hound::WavWriter`; whatever owns the
+//! real output callback just has to forward buffers into [`Recorder::push`]
+//! — this module doesn't reach into the audio backend itself, so it stays
+//! usable however samples end up getting produced.
+//!
+//! Recording never blocks the audio thread on file I/O: [`push`] sends
+//! over an unbounded channel and returns immediately, so buffers queue up
+//! if the writer thread falls behind. A buffer is dropped (with a
+//! once-per-session warning) only once the writer thread has gone away.
+
+use std::path::Path;
+use std::sync::mpsc::{self, Sender};
+use std::thread::JoinHandle;
+
+use hound::{SampleFormat, WavSpec, WavWriter};
+
+pub struct Recorder {
+    sender: Option<Sender<Vec<f32>>>,
+    handle: Option<JoinHandle<anyhow::Result<()>>>,
+    warned_dropped: bool,
+}
+
+impl Recorder {
+    /// Opens `path` for 16-bit PCM WAV output and starts the writer
+    /// thread. Matches [`crate::export::wav`]'s bit depth and clamping so
+    /// a live take and an offline export of the same material sound the
+    /// same.
+    pub fn start(path: &Path, sample_rate: u32, channels: u16) -> anyhow::Result<Self> {
+        let spec = WavSpec {
+            channels,
+            sample_rate,
+            bits_per_sample: 16,
+            sample_format: SampleFormat::Int,
+        };
+        let mut writer = WavWriter::create(path, spec)?;
+
+        let (sender, receiver) = mpsc::channel::<Vec<f32>>();
+        let handle = std::thread::spawn(move || -> anyhow::Result<()> {
+            while let Ok(buffer) = receiver.recv() {
+                for sample in buffer {
+                    writer.write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)?;
+                }
+            }
+            writer.finalize()?;
+            Ok(())
+        });
+
+        Ok(Self {
+            sender: Some(sender),
+            handle: Some(handle),
+            warned_dropped: false,
+        })
+    }
+
+    /// Forwards a buffer of interleaved samples pulled from the output
+    /// callback to the writer thread.
+    pub fn push(&mut self, buffer: &[f32]) {
+        let Some(sender) = &self.sender else {
+            return;
+        };
+        if sender.send(buffer.to_vec()).is_err() && !self.warned_dropped {
+            log::warn!("recorder thread is gone; dropping recorded audio");
+            self.warned_dropped = true;
+        }
+    }
+
+    /// Signals the writer thread to finish and blocks until the file is
+    /// flushed and closed.
+    pub fn stop(&mut self) -> anyhow::Result<()> {
+        self.sender.take();
+        if let Some(handle) = self.handle.take() {
+            handle
+                .join()
+                .map_err(|_| anyhow::anyhow!("recorder thread panicked"))??;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        let _ = self.stop();
+    }
+}
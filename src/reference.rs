@@ -0,0 +1,46 @@
+//! A reference track channel: play a known-good mix alongside the live
+//! patch to A/B the tonal balance, looping for as long as it stays
+//! enabled.
+
+use std::path::Path;
+
+use crate::note::MidiNote;
+use crate::sample::Sample;
+
+pub struct ReferenceTrack {
+    sample: Sample,
+    position: f64,
+    pub enabled: bool,
+    pub gain: f64,
+}
+
+impl ReferenceTrack {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        // The root note is irrelevant for a reference track played back at
+        // its native pitch, so any fixed value works.
+        let sample = Sample::load(path, MidiNote(69))?;
+        Ok(Self {
+            sample,
+            position: 0.0,
+            enabled: false,
+            gain: 1.0,
+        })
+    }
+
+    /// Advances playback by one sample at native pitch, looping back to
+    /// the start at the end. Returns silence while disabled.
+    pub fn tick(&mut self) -> f64 {
+        if !self.enabled {
+            return 0.0;
+        }
+        let value = match self.sample.at(self.position) {
+            Some(value) => value,
+            None => {
+                self.position = 0.0;
+                self.sample.at(0.0).unwrap_or(0.0)
+            }
+        };
+        self.position += 1.0;
+        value as f64 * self.gain
+    }
+}
@@ -0,0 +1,49 @@
+//! Accepting Lua snippets over a plain TCP socket, so an editor plugin
+//! can send code into a running `octmm play --listen <port>` session.
+//! Mirrors [`crate::osc::server::OscServer`]: a background thread does
+//! the blocking I/O and forwards complete snippets through a channel.
+
+use std::io::{BufRead, BufReader};
+use std::net::TcpListener;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+/// Owns background threads accepting connections and reading newline-
+/// delimited Lua snippets off them, forwarding each one through a
+/// channel for the main loop to `exec` against the live Lua state.
+pub struct RemoteControl {
+    snippets: Receiver<String>,
+}
+
+impl RemoteControl {
+    /// Binds to `127.0.0.1:<port>` and starts accepting connections.
+    /// Each connected client is handled on its own thread, so one slow
+    /// or silent editor connection can't starve another.
+    pub fn bind(port: u16) -> anyhow::Result<Self> {
+        let listener = TcpListener::bind(("127.0.0.1", port))?;
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { continue };
+                let tx = tx.clone();
+                thread::spawn(move || {
+                    let reader = BufReader::new(stream);
+                    for line in reader.lines() {
+                        let Ok(line) = line else { break };
+                        if tx.send(line).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+
+        Ok(Self { snippets: rx })
+    }
+
+    /// Drains every snippet received since the last call.
+    pub fn poll(&self) -> Vec<String> {
+        self.snippets.try_iter().collect()
+    }
+}
@@ -0,0 +1,80 @@
+//! `octmm repl`: an interactive Lua console dropped into a loaded
+//! project, for poking at nodes and globals without editing `main.lua`
+//! and re-running `octmm check` every time.
+
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+use mlua::{Lua, Value};
+
+use crate::lua;
+use crate::project::Project;
+
+#[derive(Debug, clap::Args)]
+pub struct ReplArgs {
+    /// Project directory to load.
+    pub project: PathBuf,
+}
+
+pub fn run(args: ReplArgs) -> anyhow::Result<()> {
+    let project = Project::load(&args.project)?;
+    let lua = Lua::new();
+
+    let nodes = Default::default();
+    let transport = Default::default();
+    let timer = Default::default();
+    let keys = Default::default();
+    lua::install(&lua, nodes, transport, timer, keys, Default::default())?;
+    lua::install_project(&lua, &project)?;
+
+    for module in project.modules()? {
+        let source = std::fs::read_to_string(&module)?;
+        lua::exec_file(&lua, &module, &source)?;
+    }
+
+    // No runner or audio module is started here, so nodes created in the
+    // REPL are buildable but not audible.
+    println!("octmm repl - {}", project.root.display());
+    println!("Type Lua expressions; `exit` or Ctrl-D to quit.");
+
+    let stdin = io::stdin();
+    let mut line_number = 0;
+    loop {
+        print!("> ");
+        io::stdout().flush()?;
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line)? == 0 {
+            println!();
+            break;
+        }
+        line_number += 1;
+
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line == "exit" || line == "quit" {
+            break;
+        }
+
+        let chunk_name = format!("repl:{line_number}");
+        match lua.load(line).set_name(chunk_name).eval::<Value>() {
+            Ok(Value::Nil) => {}
+            Ok(value) => println!("{}", describe(&value)),
+            Err(e) => eprintln!("error: {e}"),
+        }
+    }
+    Ok(())
+}
+
+fn describe(value: &Value) -> String {
+    match value {
+        Value::Nil => "nil".to_string(),
+        Value::Boolean(b) => b.to_string(),
+        Value::Integer(i) => i.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.to_str().map(str::to_string).unwrap_or_default(),
+        other => format!("{other:?}"),
+    }
+}
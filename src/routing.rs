@@ -0,0 +1,73 @@
+//! A routing matrix between DSP nets, [`crate::mixer::Mixer`] buses, and
+//! physical hardware output channels, so "which net feeds which bus" and
+//! "which bus comes out of which audio interface channel" can be
+//! reconfigured without touching the patch or the mixer itself.
+
+use std::collections::HashMap;
+
+#[derive(Default)]
+pub struct RoutingMatrix {
+    /// Each net can feed multiple buses at independent gains (a send-like
+    /// fan-out), keyed by net name.
+    net_to_bus: HashMap<String, Vec<(String, f64)>>,
+    /// Each bus maps to at most one hardware output channel; unset buses
+    /// aren't routed to hardware at all (e.g. an aux-only bus).
+    bus_to_output: HashMap<String, usize>,
+}
+
+impl RoutingMatrix {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn route_net_to_bus(&mut self, net: &str, bus: &str, gain: f64) {
+        self.net_to_bus
+            .entry(net.to_string())
+            .or_default()
+            .push((bus.to_string(), gain));
+    }
+
+    pub fn route_bus_to_output(&mut self, bus: &str, channel: usize) {
+        self.bus_to_output.insert(bus.to_string(), channel);
+    }
+
+    /// The bus inputs produced by `sample` coming out of `net`, as
+    /// `(bus, scaled sample)` pairs, ready to feed [`crate::mixer::Mixer::mix`].
+    pub fn route(&self, net: &str, sample: f64) -> Vec<(String, f64)> {
+        self.net_to_bus
+            .get(net)
+            .into_iter()
+            .flatten()
+            .map(|(bus, gain)| (bus.clone(), sample * gain))
+            .collect()
+    }
+
+    pub fn output_channel_for(&self, bus: &str) -> Option<usize> {
+        self.bus_to_output.get(bus).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_net_can_fan_out_to_multiple_buses() {
+        let mut matrix = RoutingMatrix::new();
+        matrix.route_net_to_bus("kick", "drums", 1.0);
+        matrix.route_net_to_bus("kick", "reverb", 0.2);
+
+        let routed = matrix.route("kick", 1.0);
+        assert_eq!(routed.len(), 2);
+        assert!(routed.contains(&("drums".to_string(), 1.0)));
+        assert!(routed.contains(&("reverb".to_string(), 0.2)));
+    }
+
+    #[test]
+    fn an_unrouted_bus_has_no_output_channel() {
+        let mut matrix = RoutingMatrix::new();
+        matrix.route_bus_to_output("main", 0);
+        assert_eq!(matrix.output_channel_for("main"), Some(0));
+        assert_eq!(matrix.output_channel_for("aux"), None);
+    }
+}
@@ -0,0 +1,77 @@
+//! Sample playback pitched relative to a root note, so a single recording
+//! can be played back as an instrument across the keyboard.
+
+use std::path::Path;
+
+use crate::note::MidiNote;
+
+/// A decoded mono sample and the note it was recorded at.
+pub struct Sample {
+    data: Vec<f32>,
+    root: MidiNote,
+    sample_rate: u32,
+}
+
+impl Sample {
+    pub fn load(path: &Path, root: MidiNote) -> anyhow::Result<Self> {
+        let mut reader = hound::WavReader::open(path)?;
+        let sample_rate = reader.spec().sample_rate;
+        let data: Vec<f32> = reader
+            .samples::<i16>()
+            .map(|s| s.map(|s| s as f32 / i16::MAX as f32))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            data,
+            root,
+            sample_rate,
+        })
+    }
+
+    /// A player for this sample triggered at `note`, resampling by the
+    /// ratio between `note` and the sample's root note.
+    pub fn player_for(&self, note: MidiNote) -> SamplePlayer<'_> {
+        let ratio = note.to_freq() / self.root.to_freq();
+        SamplePlayer {
+            sample: self,
+            ratio,
+            position: 0.0,
+        }
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Linearly interpolated sample at a fractional position, or `None`
+    /// once `position` has run past the end of the data.
+    pub fn at(&self, position: f64) -> Option<f32> {
+        let index = position as usize;
+        if index + 1 >= self.data.len() {
+            return None;
+        }
+        let frac = (position - index as f64) as f32;
+        Some(self.data[index] * (1.0 - frac) + self.data[index + 1] * frac)
+    }
+}
+
+/// Plays back a [`Sample`] at a fixed pitch ratio using linear
+/// interpolation between source samples.
+pub struct SamplePlayer<'a> {
+    sample: &'a Sample,
+    ratio: f64,
+    position: f64,
+}
+
+impl<'a> SamplePlayer<'a> {
+    /// Returns the next output sample, or `None` once playback has
+    /// reached the end of the source data.
+    pub fn next(&mut self) -> Option<f32> {
+        let value = self.sample.at(self.position)?;
+        self.position += self.ratio;
+        Some(value)
+    }
+}
@@ -0,0 +1,122 @@
+//! Scheduling notes to play at a future time, rather than immediately —
+//! e.g. "play this note 2 beats from now" from a Lua script.
+
+use crate::event::{EventHandle, EventRegistry};
+use crate::note::MidiNote;
+
+struct ScheduledNote {
+    at: f64,
+    note: MidiNote,
+    gain: f64,
+}
+
+/// Quantizes live-triggered events onto a beat grid, so a note fired a few
+/// milliseconds early or late from a MIDI controller or keyboard still
+/// lands in time. A single [`Groove`] applies globally, rather than being
+/// reconfigured per-event.
+#[derive(Debug, Clone, Copy)]
+pub struct Groove {
+    /// Grid spacing in seconds, e.g. one 16th note at the current tempo.
+    pub grid: f64,
+    /// How strongly to snap to the grid: `0.0` leaves timing untouched,
+    /// `1.0` snaps exactly onto the nearest grid line.
+    pub strength: f64,
+}
+
+impl Groove {
+    pub fn new(grid: f64, strength: f64) -> Self {
+        Self {
+            grid,
+            strength: strength.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Pulls `time` towards the nearest grid line by `strength`.
+    fn quantize(&self, time: f64) -> f64 {
+        if self.grid <= 0.0 {
+            return time;
+        }
+        let nearest = (time / self.grid).round() * self.grid;
+        time + (nearest - time) * self.strength
+    }
+}
+
+#[derive(Default)]
+pub struct Scheduler {
+    now: f64,
+    pending: EventRegistry<ScheduledNote>,
+    groove: Option<Groove>,
+}
+
+impl Scheduler {
+    /// Sets the global groove quantization, or `None` to disable it.
+    pub fn set_groove(&mut self, groove: Option<Groove>) {
+        self.groove = groove;
+    }
+
+    /// Schedules `note` to fire `delay_secs` from the current time,
+    /// returning a handle that can be used to cancel it.
+    pub fn schedule_in(&mut self, delay_secs: f64, note: MidiNote, gain: f64) -> EventHandle {
+        self.pending.insert(ScheduledNote {
+            at: self.now + delay_secs,
+            note,
+            gain,
+        })
+    }
+
+    /// Schedules `note` to fire as close to immediately as the current
+    /// groove allows, for events triggered live (a MIDI key, a keyboard
+    /// press) rather than composed ahead of time.
+    pub fn schedule_live(&mut self, note: MidiNote, gain: f64) -> EventHandle {
+        let at = match self.groove {
+            Some(groove) => groove.quantize(self.now).max(self.now),
+            None => self.now,
+        };
+        self.pending.insert(ScheduledNote { at, note, gain })
+    }
+
+    pub fn cancel(&mut self, handle: EventHandle) {
+        self.pending.remove(handle);
+    }
+
+    /// Advances the scheduler's clock and returns every note due to fire
+    /// since the last call, removing them from the pending set.
+    pub fn advance(&mut self, dt: f64) -> Vec<(MidiNote, f64)> {
+        self.now += dt;
+        let due: Vec<EventHandle> = self
+            .pending
+            .iter()
+            .filter(|(_, scheduled)| scheduled.at <= self.now)
+            .map(|(handle, _)| handle)
+            .collect();
+
+        due.into_iter()
+            .filter_map(|handle| self.pending.remove(handle))
+            .map(|scheduled| (scheduled.note, scheduled.gain))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fires_only_once_due_time_has_passed() {
+        let mut scheduler = Scheduler::default();
+        scheduler.schedule_in(1.0, MidiNote(60), 1.0);
+        assert!(scheduler.advance(0.5).is_empty());
+        assert_eq!(scheduler.advance(0.6).len(), 1);
+        assert!(scheduler.advance(1.0).is_empty());
+    }
+
+    #[test]
+    fn groove_snaps_live_events_towards_the_grid() {
+        let mut scheduler = Scheduler::default();
+        scheduler.set_groove(Some(Groove::new(0.25, 1.0)));
+        scheduler.advance(0.24); // now sits just before the next 0.25 grid line
+        scheduler.schedule_live(MidiNote(60), 1.0);
+        assert!(scheduler.advance(0.0).is_empty());
+        assert_eq!(scheduler.advance(0.01).len(), 1);
+    }
+}
@@ -0,0 +1,150 @@
+//! Step-sequenced playback: a fixed-length pattern of steps, each either
+//! silent or holding a note, stepped through on some external clock.
+
+use crate::note::MidiNote;
+
+/// A Renoise/IT-style effect command attached to a step, interpreted by
+/// whatever is driving playback (an [`crate::instrument::Instrument`] or a
+/// raw [`crate::dsp::DspModule`]) alongside the note itself.
+#[derive(Debug, Clone, Copy)]
+pub enum Effect {
+    /// Linearly ramps volume by this amount per tick, positive or negative.
+    VolumeSlide(f64),
+    /// Slides pitch towards the next note by this many semitones per tick.
+    Portamento(f64),
+    /// Pitch vibrato: depth in semitones, rate in Hz.
+    Vibrato { depth: f64, rate: f64 },
+    /// Re-triggers the note every `every` ticks for the rest of the step.
+    Retrigger { every: u32 },
+}
+
+/// Continuous per-note expression, MPE-style, as opposed to the discrete
+/// tracker commands in [`Effect`]. `bend` and `vibrato_depth` are
+/// semitones; `slide` is a target note to glide towards over the step.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Expression {
+    pub bend: f64,
+    pub slide: Option<MidiNote>,
+    pub vibrato_depth: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Step {
+    Rest,
+    Note(MidiNote),
+    /// A note carrying a tracker-style effect command.
+    NoteWithEffect(MidiNote, Effect),
+    /// A note carrying continuous expression data.
+    NoteWithExpression(MidiNote, Expression),
+}
+
+impl Step {
+    /// The note held by this step, if any.
+    pub fn note(&self) -> Option<MidiNote> {
+        match self {
+            Step::Rest => None,
+            Step::Note(note) => Some(*note),
+            Step::NoteWithEffect(note, _) => Some(*note),
+            Step::NoteWithExpression(note, _) => Some(*note),
+        }
+    }
+
+    /// The effect command attached to this step, if any.
+    pub fn effect(&self) -> Option<Effect> {
+        match self {
+            Step::NoteWithEffect(_, effect) => Some(*effect),
+            _ => None,
+        }
+    }
+
+    /// The expression data attached to this step, if any.
+    pub fn expression(&self) -> Option<Expression> {
+        match self {
+            Step::NoteWithExpression(_, expression) => Some(*expression),
+            _ => None,
+        }
+    }
+}
+
+/// A fixed-length pattern of steps, looped by [`Sequence::advance`].
+pub struct Sequence {
+    steps: Vec<Step>,
+    position: usize,
+}
+
+impl Sequence {
+    pub fn new(steps: Vec<Step>) -> Self {
+        Self { steps, position: 0 }
+    }
+
+    /// A rest-filled sequence of the given length, for patterns built up
+    /// step by step with [`Sequence::set_step`].
+    pub fn blank(len: usize) -> Self {
+        Self::new(vec![Step::Rest; len])
+    }
+
+    pub fn set_step(&mut self, index: usize, step: Step) {
+        self.steps[index] = step;
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn current(&self) -> Step {
+        self.steps[self.position]
+    }
+
+    /// Every step in order, for exporters that need the whole pattern at
+    /// once (e.g. a piano-roll render) rather than stepping through it.
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// Moves to the next step, wrapping around, and returns it.
+    pub fn advance(&mut self) -> Step {
+        self.position = (self.position + 1) % self.steps.len();
+        self.current()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_wraps_around() {
+        let mut seq = Sequence::new(vec![Step::Rest, Step::Note(MidiNote(60))]);
+        assert!(matches!(seq.advance(), Step::Note(_)));
+        assert!(matches!(seq.advance(), Step::Rest));
+    }
+
+    #[test]
+    fn effect_is_only_present_on_notewitheffect() {
+        let plain = Step::Note(MidiNote(60));
+        let effected = Step::NoteWithEffect(MidiNote(60), Effect::VolumeSlide(0.1));
+        assert!(plain.effect().is_none());
+        assert!(effected.effect().is_some());
+        assert_eq!(plain.note(), effected.note());
+    }
+
+    #[test]
+    fn expression_is_only_present_on_notewithexpression() {
+        let plain = Step::Note(MidiNote(60));
+        let expressed = Step::NoteWithExpression(
+            MidiNote(60),
+            Expression {
+                bend: 0.5,
+                slide: Some(MidiNote(64)),
+                vibrato_depth: 0.1,
+            },
+        );
+        assert!(plain.expression().is_none());
+        assert!(expressed.expression().is_some());
+        assert_eq!(plain.note(), expressed.note());
+    }
+}
@@ -0,0 +1,96 @@
+//! Running several [`Sequence`]s independently of each other, each on its
+//! own clock and routed to its own output bus, rather than sharing one
+//! global tick.
+
+use crate::sequence::{Sequence, Step};
+
+/// A sequence bound to its own step clock and output destination.
+pub struct Sequencer {
+    pub name: String,
+    sequence: Sequence,
+    /// Seconds per step; independent of every other sequencer's rate, so
+    /// two can run polyrhythmically against each other.
+    step_interval: f64,
+    elapsed: f64,
+    pub output_bus: String,
+}
+
+impl Sequencer {
+    pub fn new(name: impl Into<String>, sequence: Sequence, step_interval: f64, output_bus: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            sequence,
+            step_interval,
+            elapsed: 0.0,
+            output_bus: output_bus.into(),
+        }
+    }
+
+    /// Advances this sequencer's own clock by `dt`, returning the step it
+    /// landed on if that crossed a step boundary.
+    fn advance(&mut self, dt: f64) -> Option<Step> {
+        self.elapsed += dt;
+        if self.elapsed < self.step_interval {
+            return None;
+        }
+        self.elapsed -= self.step_interval;
+        Some(self.sequence.advance())
+    }
+}
+
+/// Owns a set of independent [`Sequencer`]s and advances them all on a
+/// shared wall-clock `dt`, even though each steps at its own rate.
+#[derive(Default)]
+pub struct SequencerBank {
+    sequencers: Vec<Sequencer>,
+}
+
+impl SequencerBank {
+    pub fn add(&mut self, sequencer: Sequencer) {
+        self.sequencers.push(sequencer);
+    }
+
+    /// Advances every sequencer by `dt`, returning the steps that fired
+    /// this tick as `(sequencer name, step, output bus)`.
+    pub fn advance(&mut self, dt: f64) -> Vec<(String, Step, String)> {
+        self.sequencers
+            .iter_mut()
+            .filter_map(|seq| {
+                seq.advance(dt)
+                    .map(|step| (seq.name.clone(), step, seq.output_bus.clone()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::note::MidiNote;
+
+    #[test]
+    fn sequencers_step_independently() {
+        let mut bank = SequencerBank::default();
+        bank.add(Sequencer::new(
+            "fast",
+            Sequence::new(vec![Step::Note(MidiNote(60)), Step::Rest]),
+            0.1,
+            "drums",
+        ));
+        bank.add(Sequencer::new(
+            "slow",
+            Sequence::new(vec![Step::Note(MidiNote(48)), Step::Rest]),
+            0.5,
+            "bass",
+        ));
+
+        let fired = bank.advance(0.1);
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].0, "fast");
+
+        // "fast" steps again, and "slow" reaches its first 0.5s step.
+        let fired = bank.advance(0.4);
+        assert_eq!(fired.len(), 2);
+        assert_eq!(fired[1].0, "slow");
+    }
+}
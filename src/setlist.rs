@@ -0,0 +1,57 @@
+//! Recording what happened during a session and exporting it as a
+//! human-readable setlist, for a DJ or live performer to hand to a venue
+//! afterwards.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+pub struct SetlistEntry {
+    pub at: f64,
+    pub label: String,
+}
+
+#[derive(Default)]
+pub struct Setlist {
+    entries: Vec<SetlistEntry>,
+}
+
+impl Setlist {
+    /// Records `label` (e.g. a track name, a scene change) at `at` seconds
+    /// into the session.
+    pub fn record(&mut self, at: f64, label: impl Into<String>) {
+        self.entries.push(SetlistEntry {
+            at,
+            label: label.into(),
+        });
+    }
+
+    /// Renders the setlist as `mm:ss  label` lines, one per entry, in the
+    /// order they were recorded.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for entry in &self.entries {
+            let minutes = (entry.at / 60.0) as u64;
+            let seconds = (entry.at % 60.0) as u64;
+            let _ = writeln!(out, "{minutes:02}:{seconds:02}  {}", entry.label);
+        }
+        out
+    }
+
+    pub fn export(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, self.render())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_timestamps_as_minutes_and_seconds() {
+        let mut setlist = Setlist::default();
+        setlist.record(75.0, "Opening drone");
+        setlist.record(130.5, "Breakbeat");
+        assert_eq!(setlist.render(), "01:15  Opening drone\n02:10  Breakbeat\n");
+    }
+}
@@ -0,0 +1,54 @@
+//! Where an offline render is told when a song actually ends: either an
+//! explicit `Song.SetLength(beats)` call, or a `_G.EndSong()` function the
+//! script defines, polled once per tick. Neither mechanism does anything
+//! by itself — whatever drives the render loop has to read [`SongLength`]
+//! and call [`poll_end_song`] itself, same as it has to pull samples out
+//! of the node graph itself; this module only knows how to answer "has
+//! the song ended", not how to render one.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use mlua::{Lua, Value};
+
+/// Shared with `Song.SetLength`, so a render loop that starts before the
+/// entry script has finished running can still see a length set partway
+/// through loading it.
+pub type SongLength = Rc<Cell<Option<f64>>>;
+
+/// Calls the script's `_G.EndSong` if it defined one as a function,
+/// treating anything else (including it being undefined) as "no opinion,
+/// keep going".
+pub fn poll_end_song(lua: &Lua) -> anyhow::Result<bool> {
+    let end_song: Value = lua.globals().get("EndSong")?;
+    let Value::Function(func) = end_song else {
+        return Ok(false);
+    };
+    Ok(func.call::<_, bool>(())?)
+}
+
+/// True once `buffer`'s peak amplitude falls under `threshold_db` (dBFS)
+/// — the trailing-silence condition `--tail` renders until it sees.
+pub fn is_silent(buffer: &[f32], threshold_db: f64) -> bool {
+    let peak = buffer.iter().fold(0.0_f32, |acc, &sample| acc.max(sample.abs()));
+    if peak <= 0.0 {
+        return true;
+    }
+    20.0 * (peak as f64).log10() < threshold_db
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn silence_is_detected_under_the_threshold() {
+        assert!(is_silent(&[0.0001, -0.0001], -60.0));
+        assert!(!is_silent(&[0.5, -0.5], -60.0));
+    }
+
+    #[test]
+    fn a_buffer_of_exact_zeroes_is_silent() {
+        assert!(is_silent(&[0.0, 0.0, 0.0], -60.0));
+    }
+}
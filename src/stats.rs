@@ -0,0 +1,67 @@
+//! `octmm stats`: scans a project's Lua entry script for calls into the
+//! OCTMM API and reports how often each one is used — a quick way to see
+//! which parts of the API a project actually exercises.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::project::Project;
+
+#[derive(Debug, clap::Args)]
+pub struct StatsArgs {
+    /// Project directory to scan.
+    pub project: PathBuf,
+}
+
+pub fn run(args: StatsArgs) -> anyhow::Result<()> {
+    let project = Project::load(&args.project)?;
+    let source = std::fs::read_to_string(&project.entry_script)?;
+
+    let counts = count_api_calls(&source);
+    for (name, count) in &counts {
+        println!("{count:>4}  {name}");
+    }
+    Ok(())
+}
+
+/// Tallies `Table.method(` and `handle:method(` calls — the shape every
+/// OCTMM Lua binding takes, whether it's a global table (`Noise.White()`)
+/// or a userdata method (`node:lowpass()`).
+fn count_api_calls(source: &str) -> BTreeMap<String, u32> {
+    let mut counts = BTreeMap::new();
+    let bytes = source.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_alphabetic() && bytes[i] != b'_' {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < bytes.len()
+            && (bytes[i].is_ascii_alphanumeric() || matches!(bytes[i], b'_' | b'.' | b':'))
+        {
+            i += 1;
+        }
+        let ident = &source[start..i];
+        let is_qualified_call = (ident.contains('.') || ident.contains(':'))
+            && bytes.get(i) == Some(&b'(');
+        if is_qualified_call {
+            *counts.entry(ident.to_string()).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_qualified_calls_only() {
+        let source = "Noise.White()\nnode:lowpass(800, 1.0)\nlocal x = 1\nNoise.White()\n";
+        let counts = count_api_calls(source);
+        assert_eq!(counts.get("Noise.White"), Some(&2));
+        assert_eq!(counts.get("node:lowpass"), Some(&1));
+        assert!(!counts.contains_key("x"));
+    }
+}
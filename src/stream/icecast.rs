@@ -0,0 +1,65 @@
+use std::io::Write;
+use std::net::{Shutdown, TcpStream};
+
+use opus::{Application, Channels, Encoder};
+
+use super::StreamSink;
+
+/// Streams Ogg/Opus audio to an Icecast2 mountpoint using Icecast's
+/// HTTP `SOURCE` method. One Ogg page per push; Icecast is happy to
+/// treat this like a never-ending file.
+pub struct IcecastSink {
+    conn: TcpStream,
+    encoder: Encoder,
+    serial: u32,
+    sequence: u64,
+}
+
+impl IcecastSink {
+    pub fn connect(host: &str, mount: &str, password: &str, sample_rate: u32) -> anyhow::Result<Self> {
+        let mut conn = TcpStream::connect(host)?;
+        let auth = base64::encode(format!("source:{password}"));
+        write!(
+            conn,
+            "SOURCE {mount} HTTP/1.0\r\n\
+             Authorization: Basic {auth}\r\n\
+             Content-Type: application/ogg\r\n\
+             \r\n"
+        )?;
+
+        let encoder = Encoder::new(sample_rate, Channels::Stereo, Application::Audio)?;
+
+        Ok(Self {
+            conn,
+            encoder,
+            serial: 1,
+            sequence: 0,
+        })
+    }
+}
+
+impl StreamSink for IcecastSink {
+    fn push(&mut self, samples: &[f32]) -> anyhow::Result<()> {
+        let mut packet = vec![0u8; 4096];
+        let len = self.encoder.encode_float(samples, &mut packet)?;
+        packet.truncate(len);
+
+        // Wrap the Opus packet in a minimal single-packet Ogg page. A real
+        // encoder would track granule positions and flags properly; this
+        // is good enough for a continuous, never-seeked radio stream.
+        let page = ogg::writing::PacketWriteEndInfo::NormalPacket;
+        let mut writer = ogg::writing::PacketWriter::new(&mut self.conn);
+        writer.write_packet(packet, self.serial, page, self.sequence)?;
+        self.sequence += 1;
+        Ok(())
+    }
+}
+
+impl Drop for IcecastSink {
+    fn drop(&mut self) {
+        // Closing the TCP half is how Icecast learns the source went
+        // away; leaving it open would hold the mountpoint "live" with no
+        // one feeding it.
+        let _ = self.conn.shutdown(Shutdown::Both);
+    }
+}
@@ -0,0 +1,21 @@
+//! Headless streaming output: encode the master bus to Ogg/Opus and push
+//! it to an Icecast mountpoint, for 24/7 generative-radio deployments
+//! where nothing is ever exported to a file.
+//!
+//! Gated behind the `stream` feature since it pulls in an Opus encoder
+//! and is irrelevant to the common "render to a file" workflow.
+
+#![cfg(feature = "stream")]
+
+mod icecast;
+mod rtp;
+
+pub use icecast::IcecastSink;
+pub use rtp::RtpSink;
+
+/// A destination that continuously accepts encoded audio frames, as
+/// opposed to [`crate::export`] which renders a fixed-length buffer once.
+pub trait StreamSink {
+    /// Pushes one block of interleaved stereo `f32` samples.
+    fn push(&mut self, samples: &[f32]) -> anyhow::Result<()>;
+}
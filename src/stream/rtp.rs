@@ -0,0 +1,58 @@
+use std::net::UdpSocket;
+
+use opus::{Application, Channels, Encoder};
+
+use super::StreamSink;
+
+/// Streams Opus-encoded audio as raw RTP packets (payload type 97,
+/// dynamic) to a fixed destination. No RTCP, no retransmission: this is
+/// meant for a private link to a known receiver, not the open internet.
+pub struct RtpSink {
+    socket: UdpSocket,
+    encoder: Encoder,
+    sequence: u16,
+    timestamp: u32,
+    ssrc: u32,
+}
+
+const RTP_PAYLOAD_TYPE_OPUS: u8 = 97;
+
+impl RtpSink {
+    pub fn connect(target: &str, sample_rate: u32, ssrc: u32) -> anyhow::Result<Self> {
+        let socket = UdpSocket::bind("0.0.0.0:0")?;
+        socket.connect(target)?;
+        let encoder = Encoder::new(sample_rate, Channels::Stereo, Application::Audio)?;
+        Ok(Self {
+            socket,
+            encoder,
+            sequence: 0,
+            timestamp: 0,
+            ssrc,
+        })
+    }
+
+    fn write_header(&self, buf: &mut Vec<u8>) {
+        buf.push(0x80); // version 2, no padding/extension
+        buf.push(RTP_PAYLOAD_TYPE_OPUS);
+        buf.extend_from_slice(&self.sequence.to_be_bytes());
+        buf.extend_from_slice(&self.timestamp.to_be_bytes());
+        buf.extend_from_slice(&self.ssrc.to_be_bytes());
+    }
+}
+
+impl StreamSink for RtpSink {
+    fn push(&mut self, samples: &[f32]) -> anyhow::Result<()> {
+        let mut opus_payload = vec![0u8; 4096];
+        let len = self.encoder.encode_float(samples, &mut opus_payload)?;
+        opus_payload.truncate(len);
+
+        let mut packet = Vec::with_capacity(12 + opus_payload.len());
+        self.write_header(&mut packet);
+        packet.extend_from_slice(&opus_payload);
+        self.socket.send(&packet)?;
+
+        self.sequence = self.sequence.wrapping_add(1);
+        self.timestamp = self.timestamp.wrapping_add((samples.len() / 2) as u32);
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+//! Abstracts "what time is it" so the [`crate::runner::Runner`] can be
+//! driven by a fake, deterministic clock in tests instead of wall time.
+
+use std::time::{Duration, Instant};
+
+pub trait TimeSource {
+    /// Seconds elapsed since the previous call (or since construction, on
+    /// the first call).
+    fn tick_delta(&mut self) -> f64;
+}
+
+pub struct SystemClock {
+    last: Instant,
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self { last: Instant::now() }
+    }
+}
+
+impl TimeSource for SystemClock {
+    fn tick_delta(&mut self) -> f64 {
+        let now = Instant::now();
+        let dt = now.duration_since(self.last);
+        self.last = now;
+        dt.as_secs_f64()
+    }
+}
+
+/// A clock that only advances when told to, for deterministic tests.
+#[derive(Default)]
+pub struct MockClock {
+    pending: Duration,
+}
+
+impl MockClock {
+    pub fn advance(&mut self, dt: Duration) {
+        self.pending += dt;
+    }
+}
+
+impl TimeSource for MockClock {
+    fn tick_delta(&mut self) -> f64 {
+        let dt = self.pending;
+        self.pending = Duration::ZERO;
+        dt.as_secs_f64()
+    }
+}
+
+/// Advances by the same fixed step every tick, regardless of how long the
+/// tick actually took in wall time. Used for offline/deterministic runs
+/// (`octmm play --render`, and eventually export) so a project's timers
+/// fire at exactly the same simulated times every run, independent of
+/// host load.
+pub struct FixedStepClock {
+    step: Duration,
+}
+
+impl FixedStepClock {
+    pub fn new(step: Duration) -> Self {
+        Self { step }
+    }
+}
+
+impl TimeSource for FixedStepClock {
+    fn tick_delta(&mut self) -> f64 {
+        self.step.as_secs_f64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mock_clock_only_advances_when_told() {
+        let mut clock = MockClock::default();
+        assert_eq!(clock.tick_delta(), 0.0);
+        clock.advance(Duration::from_millis(500));
+        assert_eq!(clock.tick_delta(), 0.5);
+        assert_eq!(clock.tick_delta(), 0.0);
+    }
+
+    #[test]
+    fn fixed_step_clock_always_returns_the_same_delta() {
+        let mut clock = FixedStepClock::new(Duration::from_millis(1));
+        assert_eq!(clock.tick_delta(), 0.001);
+        assert_eq!(clock.tick_delta(), 0.001);
+    }
+}
@@ -0,0 +1,598 @@
+//! Converts tick `dt` into musical time (bars and beats) at a given
+//! tempo and time signature, as a [`crate::runner::Module`], and fires
+//! Lua callbacks registered against that musical time (`Timer.every`).
+//! Tempo itself can move over time via `Tempo.RampTo`, for accelerandos
+//! and ritardandos instead of a hard `Timer.set_bpm` jump.
+//!
+//! Callbacks are scheduled by comparing `elapsed_beats` against an
+//! absolute `next_fire_beat`, not by counting down a per-tick seconds
+//! budget — a seconds countdown has to be re-derived from the current
+//! BPM every time it's rearmed, and re-deriving it from a moving BPM
+//! (`Tempo.RampTo`) or across many ticks accumulates drift. Beats are
+//! the one quantity here both sides agree on regardless of tempo, so
+//! comparing in beat-space stays exact.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use mlua::{Function, RegistryKey};
+
+use crate::context::Context;
+use crate::runner::Module;
+
+/// A standard `beats_per_bar`/`beat_unit` signature, e.g. 4/4 or 3/4.
+/// `beat_unit` is kept even though nothing reads it yet — it's part of
+/// what a time signature *is*, and every consumer of this type should be
+/// able to rely on it being there rather than assuming quarter notes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeSignature {
+    pub beats_per_bar: u32,
+    pub beat_unit: u32,
+}
+
+impl Default for TimeSignature {
+    fn default() -> Self {
+        Self {
+            beats_per_bar: 4,
+            beat_unit: 4,
+        }
+    }
+}
+
+/// A musical position addressed the way a tracker or DAW transport
+/// would: 1-based bar and beat, beat wrapping within the time
+/// signature's `beats_per_bar`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BarBeat {
+    pub bar: u32,
+    pub beat: u32,
+}
+
+/// A repeating callback scheduled against musical time. Fires once
+/// `elapsed_beats` reaches `next_fire_beat` — an absolute beat position,
+/// not a countdown, so it never has to be re-derived from a tempo that
+/// might have moved since it was last armed.
+struct Callback {
+    /// Assigned at registration, purely so a failing callback can be
+    /// named in logs without needing a Lua-supplied label.
+    id: u64,
+    interval_beats: f64,
+    next_fire_beat: f64,
+    /// Alternates every firing so swing can push every *other*
+    /// subdivision late without needing the caller to track parity.
+    swung: bool,
+    /// `None` repeats forever (`Timer.every`); `Some(n)` counts down and
+    /// the callback is dropped once it hits zero (`Timer.after`,
+    /// `Timer.once`, or `Timer.every`'s `repeat_count` option).
+    remaining: Option<u32>,
+    /// Lower fires first among callbacks due on the same tick; ties keep
+    /// registration order. Defaults to `0`.
+    priority: i32,
+    /// Set once a firing raises a Lua error, so the offending callback
+    /// stops being scheduled instead of spamming the log every tick —
+    /// one broken callback shouldn't take the rest of the timers (or the
+    /// audio thread) down with it.
+    disabled: bool,
+    func: RegistryKey,
+}
+
+/// An in-progress `Tempo.RampTo` — linear interpolation from `start_bpm`
+/// to `end_bpm` over `total_beats` of *elapsed* beats, so a ramp still
+/// takes the number of beats it promised even while it's changing the
+/// rate those beats pass at.
+struct TempoRamp {
+    start_bpm: f64,
+    end_bpm: f64,
+    total_beats: f64,
+    elapsed_beats: f64,
+}
+
+pub struct TimerModule {
+    bpm: f64,
+    signature: TimeSignature,
+    elapsed_beats: f64,
+    /// Fraction of a subdivision's interval that every second firing is
+    /// pushed back by, e.g. `0.15` for a subtle triplet-ish swing.
+    swing: f64,
+    callbacks: Vec<Callback>,
+    ramp: Option<TempoRamp>,
+    next_callback_id: u64,
+}
+
+impl Default for TimerModule {
+    /// 120bpm, 4/4 — a reasonable starting point for a project that
+    /// hasn't called `Timer.set_bpm` yet.
+    fn default() -> Self {
+        Self::new(120.0, TimeSignature::default())
+    }
+}
+
+impl TimerModule {
+    pub fn new(bpm: f64, signature: TimeSignature) -> Self {
+        Self {
+            bpm,
+            signature,
+            elapsed_beats: 0.0,
+            swing: 0.0,
+            callbacks: Vec::new(),
+            ramp: None,
+            next_callback_id: 0,
+        }
+    }
+
+    pub fn set_bpm(&mut self, bpm: f64) {
+        self.bpm = bpm;
+        // An explicit tempo jump overrides whatever ramp was in flight,
+        // the same way setting a variable overrides an in-flight tween.
+        self.ramp = None;
+    }
+
+    /// Interpolates from the current BPM to `target_bpm` over the next
+    /// `beats` beats, rather than jumping there immediately. Scheduled
+    /// callbacks don't need to know about this at all — `next_fire_beat`
+    /// is a beat position, not a number of seconds, so it stays correct
+    /// no matter how the BPM wobbles on the way there.
+    pub fn ramp_to(&mut self, target_bpm: f64, beats: f64) {
+        self.ramp = Some(TempoRamp {
+            start_bpm: self.bpm,
+            end_bpm: target_bpm,
+            total_beats: beats.max(f64::EPSILON),
+            elapsed_beats: 0.0,
+        });
+    }
+
+    pub fn set_signature(&mut self, signature: TimeSignature) {
+        self.signature = signature;
+    }
+
+    pub fn set_swing(&mut self, swing: f64) {
+        self.swing = swing;
+    }
+
+    pub fn elapsed_beats(&self) -> f64 {
+        self.elapsed_beats
+    }
+
+    pub fn bpm(&self) -> f64 {
+        self.bpm
+    }
+
+    /// The current position as a 1-based bar/beat pair.
+    pub fn position(&self) -> BarBeat {
+        let beats_per_bar = f64::from(self.signature.beats_per_bar.max(1));
+        let bar = (self.elapsed_beats / beats_per_bar).floor() as u32;
+        let beat = (self.elapsed_beats % beats_per_bar).floor() as u32;
+        BarBeat {
+            bar: bar + 1,
+            beat: beat + 1,
+        }
+    }
+
+    /// Registers `func` to fire every `interval_beats` beats, starting
+    /// one interval from now, forever, at `priority` — lower priorities
+    /// fire first among callbacks due on the same tick. Returns an id
+    /// that [`TimerModule::cancel`] can later use to stop it.
+    pub fn add_callback(
+        &mut self,
+        lua: &mlua::Lua,
+        interval_beats: f64,
+        priority: i32,
+        func: Function,
+    ) -> anyhow::Result<u64> {
+        self.push_callback(lua, interval_beats, None, priority, func)
+    }
+
+    /// Registers `func` to fire once, `delay_beats` beats from now, at
+    /// `priority` — lower priorities fire first among callbacks due on
+    /// the same tick.
+    pub fn add_once(
+        &mut self,
+        lua: &mlua::Lua,
+        delay_beats: f64,
+        priority: i32,
+        func: Function,
+    ) -> anyhow::Result<u64> {
+        self.push_callback(lua, delay_beats, Some(1), priority, func)
+    }
+
+    /// Registers `func` to fire every `interval_beats` beats, stopping
+    /// after `repeat_count` firings and at `priority` — the
+    /// `repeat_count`/priority options behind `Timer.every`'s extra
+    /// arguments.
+    pub fn add_limited(
+        &mut self,
+        lua: &mlua::Lua,
+        interval_beats: f64,
+        repeat_count: u32,
+        priority: i32,
+        func: Function,
+    ) -> anyhow::Result<u64> {
+        self.push_callback(lua, interval_beats, Some(repeat_count), priority, func)
+    }
+
+    /// Stops a callback registered via `add_callback`/`add_once`/
+    /// `add_limited` before it would otherwise finish on its own. A
+    /// stale or already-finished id is silently ignored, the same way
+    /// clearing an already-cleared timeout would be elsewhere.
+    pub fn cancel(&mut self, id: u64) {
+        self.callbacks.retain(|callback| callback.id != id);
+    }
+
+    fn push_callback(
+        &mut self,
+        lua: &mlua::Lua,
+        interval_beats: f64,
+        remaining: Option<u32>,
+        priority: i32,
+        func: Function,
+    ) -> anyhow::Result<u64> {
+        let key = lua.create_registry_value(func)?;
+        let id = self.next_callback_id;
+        self.next_callback_id += 1;
+        self.callbacks.push(Callback {
+            id,
+            interval_beats,
+            next_fire_beat: self.elapsed_beats + interval_beats,
+            swung: false,
+            remaining,
+            priority,
+            disabled: false,
+            func: key,
+        });
+        Ok(id)
+    }
+
+    fn advance(&mut self, dt: f64) {
+        let beat_delta = dt * (self.bpm / 60.0);
+        self.elapsed_beats += beat_delta;
+
+        if let Some(ramp) = &mut self.ramp {
+            ramp.elapsed_beats += beat_delta;
+            let fraction = (ramp.elapsed_beats / ramp.total_beats).min(1.0);
+            self.bpm = ramp.start_bpm + (ramp.end_bpm - ramp.start_bpm) * fraction;
+            if fraction >= 1.0 {
+                self.ramp = None;
+            }
+        }
+    }
+}
+
+impl Module for TimerModule {
+    fn update(&mut self, ctx: &Context) -> anyhow::Result<()> {
+        self.advance(ctx.dt);
+
+        let elapsed_beats = self.elapsed_beats;
+        let mut due: Vec<usize> = self
+            .callbacks
+            .iter()
+            .enumerate()
+            .filter(|(_, callback)| !callback.disabled && elapsed_beats >= callback.next_fire_beat)
+            .map(|(index, _)| index)
+            .collect();
+        // A stable sort on priority alone keeps same-beat callbacks in
+        // registration order when their priorities tie, instead of
+        // whatever order they happen to sit in `callbacks`.
+        due.sort_by_key(|&index| self.callbacks[index].priority);
+
+        let swing = self.swing;
+        for index in due {
+            let callback = &mut self.callbacks[index];
+            let func: Function = ctx.lua.registry_value(&callback.func)?;
+
+            // A broken callback must not take the whole tick down with
+            // it — log it, disable it, and keep everything else (and the
+            // audio it's driving) running.
+            if let Err(err) = crate::lua::call_with_traceback(ctx.lua, &func) {
+                log::error!(
+                    "timer callback #{} ({} beats) failed, disabling it: {err}",
+                    callback.id,
+                    callback.interval_beats
+                );
+                callback.disabled = true;
+                continue;
+            }
+
+            if let Some(remaining) = &mut callback.remaining {
+                *remaining -= 1;
+            }
+
+            callback.swung = !callback.swung;
+            let swing_offset = if callback.swung {
+                callback.interval_beats * swing
+            } else {
+                0.0
+            };
+            callback.next_fire_beat += callback.interval_beats + swing_offset;
+        }
+
+        self.callbacks.retain(|callback| callback.remaining != Some(0));
+
+        Ok(())
+    }
+}
+
+/// Shared handle to a [`TimerModule`], so `Timer.every(...)` (registered
+/// against the Lua state before the runner exists) and the runner's own
+/// tick loop (which owns the module afterwards) can reach the same
+/// instance.
+pub type SharedTimer = Rc<RefCell<TimerModule>>;
+
+impl Module for SharedTimer {
+    fn update(&mut self, ctx: &Context) -> anyhow::Result<()> {
+        self.borrow_mut().update(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_quarter_note_at_120bpm_takes_half_a_second() {
+        let mut timer = TimerModule::new(120.0, TimeSignature::default());
+        timer.advance(0.5);
+        assert!((timer.elapsed_beats() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn position_wraps_beats_into_bars_by_the_time_signature() {
+        let mut timer = TimerModule::new(120.0, TimeSignature {
+            beats_per_bar: 3,
+            beat_unit: 4,
+        });
+        // 4 beats in: bar 2, beat 2 (1-based; bar 1 used up 3 beats).
+        timer.advance(2.0);
+        assert_eq!(timer.position(), BarBeat { bar: 2, beat: 2 });
+    }
+
+    #[test]
+    fn position_starts_at_bar_one_beat_one() {
+        let timer = TimerModule::new(120.0, TimeSignature::default());
+        assert_eq!(timer.position(), BarBeat { bar: 1, beat: 1 });
+    }
+
+    #[test]
+    fn add_once_fires_exactly_once_no_matter_how_long_it_runs() {
+        let lua = mlua::Lua::new();
+        let count = std::rc::Rc::new(std::cell::Cell::new(0));
+        let count_for_closure = count.clone();
+        let func = lua
+            .create_function(move |_, ()| {
+                count_for_closure.set(count_for_closure.get() + 1);
+                Ok(())
+            })
+            .unwrap();
+
+        let mut timer = TimerModule::new(120.0, TimeSignature::default());
+        timer.add_once(&lua, 1.0, 0, func).unwrap();
+
+        let ctx = Context::new(&lua, 0.6);
+        for _ in 0..5 {
+            timer.update(&ctx).unwrap();
+        }
+        assert_eq!(count.get(), 1);
+        assert!(timer.callbacks.is_empty());
+    }
+
+    #[test]
+    fn add_limited_stops_after_repeat_count_firings() {
+        let lua = mlua::Lua::new();
+        let count = std::rc::Rc::new(std::cell::Cell::new(0));
+        let count_for_closure = count.clone();
+        let func = lua
+            .create_function(move |_, ()| {
+                count_for_closure.set(count_for_closure.get() + 1);
+                Ok(())
+            })
+            .unwrap();
+
+        let mut timer = TimerModule::new(120.0, TimeSignature::default());
+        timer.add_limited(&lua, 1.0, 3, 0, func).unwrap();
+
+        let ctx = Context::new(&lua, 0.6);
+        for _ in 0..10 {
+            timer.update(&ctx).unwrap();
+        }
+        assert_eq!(count.get(), 3);
+        assert!(timer.callbacks.is_empty());
+    }
+
+    #[test]
+    fn cancel_stops_a_callback_before_it_would_have_fired() {
+        let lua = mlua::Lua::new();
+        let count = std::rc::Rc::new(std::cell::Cell::new(0));
+        let count_for_closure = count.clone();
+        let func = lua
+            .create_function(move |_, ()| {
+                count_for_closure.set(count_for_closure.get() + 1);
+                Ok(())
+            })
+            .unwrap();
+
+        let mut timer = TimerModule::new(120.0, TimeSignature::default());
+        let id = timer.add_callback(&lua, 1.0, 0, func).unwrap();
+        timer.cancel(id);
+
+        let ctx = Context::new(&lua, 0.6);
+        timer.update(&ctx).unwrap();
+        assert_eq!(count.get(), 0);
+        assert!(timer.callbacks.is_empty());
+    }
+
+    #[test]
+    fn firing_calls_the_registered_lua_function() {
+        let lua = mlua::Lua::new();
+        let count = std::rc::Rc::new(std::cell::Cell::new(0));
+        let count_for_closure = count.clone();
+        let func = lua
+            .create_function(move |_, ()| {
+                count_for_closure.set(count_for_closure.get() + 1);
+                Ok(())
+            })
+            .unwrap();
+
+        let mut timer = TimerModule::new(120.0, TimeSignature::default());
+        timer.add_callback(&lua, 1.0, 0, func).unwrap();
+
+        let ctx = Context::new(&lua, 0.6);
+        timer.update(&ctx).unwrap();
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn same_beat_callbacks_fire_in_priority_order() {
+        let lua = mlua::Lua::new();
+        let order = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+
+        let mut timer = TimerModule::new(120.0, TimeSignature::default());
+        for (label, priority) in [("low", 10), ("high", -10), ("mid", 0)] {
+            let order = order.clone();
+            let func = lua
+                .create_function(move |_, ()| {
+                    order.borrow_mut().push(label);
+                    Ok(())
+                })
+                .unwrap();
+            timer.add_once(&lua, 1.0, priority, func).unwrap();
+        }
+
+        let ctx = Context::new(&lua, 10.0);
+        timer.update(&ctx).unwrap();
+        assert_eq!(*order.borrow(), vec!["high", "mid", "low"]);
+    }
+
+    #[test]
+    fn ramp_to_interpolates_bpm_linearly_over_elapsed_beats() {
+        let mut timer = TimerModule::new(120.0, TimeSignature::default());
+        timer.ramp_to(240.0, 4.0);
+
+        // One beat in at the starting rate; a quarter of the way through
+        // a 4-beat ramp should land roughly a quarter of the way from
+        // 120 to 240.
+        timer.advance(0.5);
+        assert!(timer.bpm > 120.0 && timer.bpm < 240.0);
+    }
+
+    #[test]
+    fn ramp_to_settles_exactly_on_the_target_bpm() {
+        let mut timer = TimerModule::new(120.0, TimeSignature::default());
+        timer.ramp_to(240.0, 2.0);
+
+        for _ in 0..100 {
+            timer.advance(0.1);
+        }
+        assert!((timer.bpm - 240.0).abs() < 1e-9);
+        assert!(timer.ramp.is_none());
+    }
+
+    #[test]
+    fn set_bpm_cancels_an_in_flight_ramp() {
+        let mut timer = TimerModule::new(120.0, TimeSignature::default());
+        timer.ramp_to(240.0, 4.0);
+        timer.set_bpm(90.0);
+        timer.advance(1.0);
+        assert!((timer.bpm - 90.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn swing_lengthens_the_gap_after_a_swung_firing() {
+        let lua = mlua::Lua::new();
+        let func = lua.create_function(|_, ()| Ok(())).unwrap();
+
+        let mut timer = TimerModule::new(120.0, TimeSignature::default());
+        timer.set_swing(0.5);
+        timer.add_callback(&lua, 1.0, 0, func).unwrap();
+
+        let unswung_gap = 1.0;
+        assert!((timer.callbacks[0].next_fire_beat - unswung_gap).abs() < 1e-9);
+
+        // Half a second at 120bpm is exactly one beat, so this fires.
+        let ctx = Context::new(&lua, 0.51);
+        timer.update(&ctx).unwrap();
+
+        let swung_gap = timer.callbacks[0].next_fire_beat - timer.elapsed_beats();
+        assert!(
+            swung_gap > unswung_gap,
+            "a swung firing should push its next gap later than the base interval"
+        );
+    }
+
+    #[test]
+    fn a_failing_callback_is_disabled_instead_of_propagating_or_repeating() {
+        let lua = mlua::Lua::new();
+        let failing = lua.create_function(|_, ()| Err::<(), _>(mlua::Error::RuntimeError("boom".into()))).unwrap();
+        let count = std::rc::Rc::new(std::cell::Cell::new(0));
+        let count_for_closure = count.clone();
+        let healthy = lua
+            .create_function(move |_, ()| {
+                count_for_closure.set(count_for_closure.get() + 1);
+                Ok(())
+            })
+            .unwrap();
+
+        let mut timer = TimerModule::new(120.0, TimeSignature::default());
+        timer.add_callback(&lua, 1.0, 0, failing).unwrap();
+        timer.add_callback(&lua, 1.0, 0, healthy).unwrap();
+
+        let ctx = Context::new(&lua, 0.6);
+        for _ in 0..5 {
+            timer.update(&ctx).unwrap();
+        }
+
+        assert!(timer.callbacks[0].disabled);
+        // The failing callback fired (and failed) exactly once; the
+        // healthy one kept firing on schedule every tick since.
+        assert_eq!(count.get(), 5);
+    }
+
+    #[test]
+    fn a_bpm_change_mid_song_does_not_shift_a_callbacks_scheduled_beat() {
+        let lua = mlua::Lua::new();
+        let func = lua.create_function(|_, ()| Ok(())).unwrap();
+
+        let mut timer = TimerModule::new(120.0, TimeSignature::default());
+        timer.add_callback(&lua, 1.0, 0, func).unwrap();
+        let scheduled_beat = timer.callbacks[0].next_fire_beat;
+
+        // Changing tempo mid-flight must not perturb a beat-space
+        // schedule that was never expressed in seconds to begin with.
+        timer.set_bpm(90.0);
+        assert_eq!(timer.callbacks[0].next_fire_beat, scheduled_beat);
+    }
+
+    #[test]
+    fn firing_stays_on_schedule_across_thousands_of_irregular_ticks() {
+        let lua = mlua::Lua::new();
+        let count = std::rc::Rc::new(std::cell::Cell::new(0));
+        let count_for_closure = count.clone();
+        let func = lua
+            .create_function(move |_, ()| {
+                count_for_closure.set(count_for_closure.get() + 1);
+                Ok(())
+            })
+            .unwrap();
+
+        let mut timer = TimerModule::new(120.0, TimeSignature::default());
+        timer.add_callback(&lua, 1.0, 0, func).unwrap();
+
+        // An awkward, non-divisor dt accumulates rounding error in
+        // `elapsed_beats` the same way it would have in the old
+        // per-tick seconds countdown; the fix is that the schedule
+        // itself is compared in beats, so it can't drift independently
+        // of that shared accumulator.
+        let dt = 0.0137;
+        let ctx = Context::new(&lua, dt);
+        let ticks = (60.0 / dt) as usize; // ~60 simulated seconds.
+        for _ in 0..ticks {
+            timer.update(&ctx).unwrap();
+        }
+
+        // At 120bpm a quarter-note callback fires twice a second.
+        let expected = (ticks as f64 * dt * 2.0) as i64;
+        assert!(
+            (count.get() as i64 - expected).abs() <= 1,
+            "expected around {expected} firings, got {}",
+            count.get()
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! Importing Protracker `.mod` modules: the sample headers become
+//! [`ModSample`]s and each pattern becomes a [`Sequence`].
+//! `.xm` is intentionally out of scope here — its sample format and
+//! pattern compression are different enough to warrant its own importer
+//! once someone actually needs it.
+
+use std::path::Path;
+
+use crate::note::MidiNote;
+use crate::sequence::{Sequence, Step};
+
+const MOD_HEADER_LEN: usize = 1084;
+const NUM_CHANNELS: usize = 4; // classic 4-channel Protracker format
+const ROWS_PER_PATTERN: usize = 64;
+
+pub struct ModSample {
+    pub name: String,
+    pub data: Vec<f32>,
+}
+
+pub struct ModModule {
+    pub title: String,
+    pub samples: Vec<ModSample>,
+    pub patterns: Vec<Sequence>,
+}
+
+/// Parses a `.mod` file's sample table and pattern note data. Effect
+/// commands are dropped on the floor for now — only which note plays on
+/// which step, per channel 0, is kept.
+pub fn import(path: &Path) -> anyhow::Result<ModModule> {
+    let bytes = std::fs::read(path)?;
+    anyhow::ensure!(bytes.len() > MOD_HEADER_LEN, "file too short to be a .mod");
+
+    let title = read_fixed_string(&bytes[0..20]);
+
+    let mut samples = Vec::new();
+    let mut sample_lengths = Vec::new();
+    for i in 0..31 {
+        let offset = 20 + i * 30;
+        let name = read_fixed_string(&bytes[offset..offset + 22]);
+        let length_words = u16::from_be_bytes([bytes[offset + 22], bytes[offset + 23]]) as usize;
+        sample_lengths.push(length_words * 2);
+        samples.push(ModSample {
+            name,
+            data: Vec::new(),
+        });
+    }
+
+    let num_patterns = bytes[950] as usize;
+    let pattern_table = &bytes[952..952 + 128];
+
+    let pattern_data_start = MOD_HEADER_LEN;
+    let pattern_size = NUM_CHANNELS * ROWS_PER_PATTERN * 4;
+    let mut patterns = Vec::with_capacity(num_patterns);
+    for p in 0..num_patterns {
+        let start = pattern_data_start + p * pattern_size;
+        let mut sequence = Sequence::blank(ROWS_PER_PATTERN);
+        for row in 0..ROWS_PER_PATTERN {
+            let cell_offset = start + row * NUM_CHANNELS * 4;
+            if cell_offset + 4 > bytes.len() {
+                continue;
+            }
+            let cell = &bytes[cell_offset..cell_offset + 4];
+            let period = (((cell[0] & 0x0F) as u16) << 8) | cell[1] as u16;
+            if let Some(note) = period_to_note(period) {
+                sequence.set_step(row, Step::Note(note));
+            }
+        }
+        patterns.push(sequence);
+    }
+
+    // Sample audio data immediately follows the pattern data, in the
+    // same order as the sample headers.
+    let mut offset = pattern_data_start + num_patterns * pattern_size;
+    let _ = pattern_table; // pattern-play order isn't used yet
+    for (sample, &length) in samples.iter_mut().zip(sample_lengths.iter()) {
+        let end = (offset + length).min(bytes.len());
+        sample.data = bytes[offset..end]
+            .iter()
+            .map(|&b| (b as i8) as f32 / i8::MAX as f32)
+            .collect();
+        offset = end;
+    }
+
+    Ok(ModModule {
+        title,
+        samples,
+        patterns,
+    })
+}
+
+fn read_fixed_string(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+/// Protracker periods map to notes on a fixed lookup; this covers the
+/// standard 3-octave range rather than every edge-case period value.
+fn period_to_note(period: u16) -> Option<MidiNote> {
+    const PERIOD_TABLE: [u16; 36] = [
+        856, 808, 762, 720, 678, 640, 604, 570, 538, 508, 480, 453, 428, 404, 381, 360, 339, 320,
+        302, 285, 269, 254, 240, 226, 214, 202, 190, 180, 170, 160, 151, 143, 135, 127, 120, 113,
+    ];
+    if period == 0 {
+        return None;
+    }
+    PERIOD_TABLE
+        .iter()
+        .position(|&p| p == period)
+        .map(|index| MidiNote(36 + index as u8)) // C-2 in Protracker is MIDI note 36
+}
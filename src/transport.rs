@@ -0,0 +1,107 @@
+//! A project's song position: play/pause state, current time, and an
+//! optional loop region — independent of whatever's actually advancing
+//! it tick to tick (live playback, or a headless render).
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoopRegion {
+    pub start: f64,
+    pub end: f64,
+}
+
+#[derive(Debug)]
+pub struct Transport {
+    position: f64,
+    playing: bool,
+    loop_region: Option<LoopRegion>,
+}
+
+impl Default for Transport {
+    fn default() -> Self {
+        Self {
+            position: 0.0,
+            playing: true,
+            loop_region: None,
+        }
+    }
+}
+
+impl Transport {
+    pub fn play(&mut self) {
+        self.playing = true;
+    }
+
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    pub fn position(&self) -> f64 {
+        self.position
+    }
+
+    pub fn seek(&mut self, position: f64) {
+        self.position = position.max(0.0);
+    }
+
+    pub fn set_loop(&mut self, start: f64, end: f64) {
+        self.loop_region = Some(LoopRegion {
+            start: start.min(end).max(0.0),
+            end: start.max(end),
+        });
+    }
+
+    pub fn clear_loop(&mut self) {
+        self.loop_region = None;
+    }
+
+    /// Advances the position by `dt` if playing, wrapping any overshoot
+    /// past the loop region's end back around to its start. Does
+    /// nothing while paused.
+    pub fn advance(&mut self, dt: f64) {
+        if !self.playing {
+            return;
+        }
+        self.position += dt;
+        if let Some(region) = self.loop_region {
+            let span = (region.end - region.start).max(f64::EPSILON);
+            if self.position >= region.end {
+                let overshoot = (self.position - region.end) % span;
+                self.position = region.start + overshoot;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pausing_stops_position_from_advancing() {
+        let mut transport = Transport::default();
+        transport.advance(1.0);
+        transport.pause();
+        transport.advance(1.0);
+        assert_eq!(transport.position(), 1.0);
+    }
+
+    #[test]
+    fn seek_jumps_directly_regardless_of_play_state() {
+        let mut transport = Transport::default();
+        transport.pause();
+        transport.seek(4.5);
+        assert_eq!(transport.position(), 4.5);
+    }
+
+    #[test]
+    fn loop_region_wraps_overshoot_back_to_its_start() {
+        let mut transport = Transport::default();
+        transport.set_loop(1.0, 2.0);
+        transport.seek(1.5);
+        transport.advance(0.75);
+        assert!((transport.position() - 1.25).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,146 @@
+//! `octmm play --ui`: a status display redrawn over the terminal each
+//! tick instead of the silent console `play` runs with by default —
+//! elapsed time, bar/beat, BPM, CPU/tick jitter, and the patch's recent
+//! `print` output.
+//!
+//! Raw mode and the alternate screen are only entered the first time
+//! this module actually ticks, the same lazy approach
+//! [`crate::keys::KeysModule`] takes, so nothing but `play --ui` itself
+//! ever has its terminal taken over.
+//!
+//! Active voice count isn't shown yet — nothing in the engine tracks
+//! how many DSP nodes are currently sounding, only how many have been
+//! created — so the panel reads `n/a` there until that exists.
+
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::io::Stdout;
+use std::rc::Rc;
+use std::time::Instant;
+
+use crossterm::execute;
+use crossterm::terminal::{EnterAlternateScreen, LeaveAlternateScreen};
+use mlua::{Lua, Value, Variadic};
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use ratatui::Terminal;
+
+use crate::context::Context;
+use crate::runner::{JitterStats, Module};
+use crate::timer::SharedTimer;
+
+/// How many of the most recently `print`ed lines to keep around for the
+/// log panel; older ones just scroll off.
+const PRINT_LOG_LINES: usize = 200;
+
+/// Lines captured from the patch's own `print` calls, oldest first.
+/// Shared with the closure installed over the Lua global in
+/// [`install_print_capture`], since that's the only thing that ever
+/// writes to it.
+pub type PrintLog = Rc<RefCell<VecDeque<String>>>;
+
+/// Replaces Lua's global `print` with one that appends to `log` instead
+/// of writing to stdout, since the TUI owns the whole terminal while
+/// it's running and a stray `print` would tear up the display rather
+/// than scroll past it like it does in the console build.
+pub fn install_print_capture(lua: &Lua, log: PrintLog) -> anyhow::Result<()> {
+    let func = lua.create_function(move |lua, args: Variadic<Value>| {
+        let tostring: mlua::Function = lua.globals().get("tostring")?;
+        let mut parts = Vec::with_capacity(args.len());
+        for value in args.iter() {
+            parts.push(tostring.call::<_, String>(value.clone())?);
+        }
+        let mut log = log.borrow_mut();
+        log.push_back(parts.join("\t"));
+        if log.len() > PRINT_LOG_LINES {
+            log.pop_front();
+        }
+        Ok(())
+    })?;
+    lua.globals().set("print", func)?;
+    Ok(())
+}
+
+/// Draws the status display once per tick. Holds the [`SharedTimer`]
+/// and [`JitterStats`] handles `play` already has lying around rather
+/// than being handed fresh copies of bar/beat/BPM/jitter every tick.
+pub struct TuiModule {
+    terminal: Option<Terminal<CrosstermBackend<Stdout>>>,
+    timer: SharedTimer,
+    jitter: Rc<Cell<JitterStats>>,
+    print_log: PrintLog,
+    started: Instant,
+}
+
+impl TuiModule {
+    pub fn new(timer: SharedTimer, jitter: Rc<Cell<JitterStats>>, print_log: PrintLog) -> Self {
+        Self {
+            terminal: None,
+            timer,
+            jitter,
+            print_log,
+            started: Instant::now(),
+        }
+    }
+
+    fn draw(&mut self) -> anyhow::Result<()> {
+        let position = self.timer.borrow().position();
+        let bpm = self.timer.borrow().bpm();
+        let jitter = self.jitter.get();
+        let elapsed = self.started.elapsed().as_secs_f64();
+        let print_log = self.print_log.borrow();
+
+        let Some(terminal) = &mut self.terminal else {
+            return Ok(());
+        };
+        terminal.draw(|frame| {
+            let area = frame.size();
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(3), Constraint::Min(0)])
+                .split(area);
+
+            let header = Paragraph::new(format!(
+                "elapsed {elapsed:>8.1}s   bar {} beat {}   {bpm:>6.1} bpm   \
+                 voices n/a   dt {:.2}ms jitter {:.2}ms",
+                position.bar,
+                position.beat,
+                jitter.last_dt * 1000.0,
+                jitter.jitter * 1000.0,
+            ))
+            .block(Block::default().borders(Borders::ALL).title("octmm"));
+            frame.render_widget(header, chunks[0]);
+
+            let items: Vec<ListItem> = print_log
+                .iter()
+                .rev()
+                .map(|line| ListItem::new(line.as_str()))
+                .collect();
+            let log = List::new(items).block(Block::default().borders(Borders::ALL).title("print"));
+            frame.render_widget(log, chunks[1]);
+        })?;
+        Ok(())
+    }
+}
+
+impl Module for TuiModule {
+    fn update(&mut self, _ctx: &Context) -> anyhow::Result<()> {
+        if self.terminal.is_none() {
+            crossterm::terminal::enable_raw_mode()?;
+            let mut stdout = std::io::stdout();
+            execute!(stdout, EnterAlternateScreen)?;
+            self.terminal = Some(Terminal::new(CrosstermBackend::new(stdout))?);
+        }
+        self.draw()
+    }
+}
+
+impl Drop for TuiModule {
+    fn drop(&mut self) {
+        if self.terminal.is_some() {
+            let _ = execute!(std::io::stdout(), LeaveAlternateScreen);
+            let _ = crossterm::terminal::disable_raw_mode();
+        }
+    }
+}